@@ -10,7 +10,7 @@ fn main() {
     let path = Path::new("assets/config");
 
     copy_dir_recursive(
-        &path,
+        path,
         &out_dir.join(path)
     ).unwrap();
 
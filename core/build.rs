@@ -1,90 +1,293 @@
 use std::{fs, io};
+use std::fmt::{Debug, Formatter};
 use std::fs::File;
 use std::io::Write;
-use std::path::Path;
+use std::path::{Path, PathBuf};
 
 use mx_chain_simulator_interface_config_rs::CONFIG_PATH;
+use sha2::{Digest, Sha256};
 
 const CHAIN_SIMULATOR_NAME: &str = "chainsimulator";
 
-#[allow(dead_code)]
-const CHAIN_SIMULATOR_DARWIN_AMD64_NAME: &str = "chainsimulator_darwin_amd64";
-#[allow(dead_code)]
-const CHAIN_SIMULATOR_LINUX_AMD64_NAME: &str = "chainsimulator_linux_amd64";
-#[allow(dead_code)]
-const LIBWASMER_LINUX_AMD64_NAME: &str = "libwasmer_linux_amd64.so";
-#[allow(dead_code)]
-const LIBWASMER_DARWIN_AMD64_NAME: &str = "libwasmer_darwin_amd64.dylib";
+struct Platform {
+    target_os: &'static str,
+    target_arch: &'static str,
+    name: &'static str,
+    chain_simulator_name: &'static str,
+    libraries: &'static [&'static str],
+}
+
+const PLATFORMS: &[Platform] = &[
+    Platform {
+        target_os: "linux",
+        target_arch: "x86_64",
+        name: "linux_amd64",
+        chain_simulator_name: "chainsimulator_linux_amd64",
+        libraries: &["libwasmer_linux_amd64.so"],
+    },
+    Platform {
+        target_os: "macos",
+        target_arch: "x86_64",
+        name: "darwin_amd64",
+        chain_simulator_name: "chainsimulator_darwin_amd64",
+        libraries: &["libwasmer_darwin_amd64.dylib"],
+    },
+    // No arm64 binaries are published. This fallback runs the amd64 ones through Rosetta, it is not native support.
+    // Other targets have no row so they reach the runtime UnsupportedOSAndArch error, unless a binary is provided.
+    Platform {
+        target_os: "macos",
+        target_arch: "aarch64",
+        name: "darwin_amd64",
+        chain_simulator_name: "chainsimulator_darwin_amd64",
+        libraries: &["libwasmer_darwin_amd64.dylib"],
+    },
+];
+
+const CHECKSUMS_MANIFEST_PATH: &str = "assets.sha256";
+
+const BIN_ENV_VAR: &str = "MX_CHAIN_SIMULATOR_BIN";
+const ASSETS_DIR_ENV_VAR: &str = "MX_CHAIN_SIMULATOR_ASSETS_DIR";
+const CACHE_DIR_ENV_VAR: &str = "MX_CHAIN_SIMULATOR_CACHE_DIR";
+const OFFLINE_ENV_VAR: &str = "MX_CHAIN_SIMULATOR_OFFLINE";
+
+struct BuildError(String);
+
+impl Debug for BuildError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+struct Asset {
+    source_name: &'static str,
+    dest_name: &'static str,
+}
+
+fn main() -> Result<(), BuildError> {
+    println!("cargo:rerun-if-changed=build.rs");
+    println!("cargo:rerun-if-changed={CHECKSUMS_MANIFEST_PATH}");
+    for env_var in [BIN_ENV_VAR, ASSETS_DIR_ENV_VAR, CACHE_DIR_ENV_VAR, OFFLINE_ENV_VAR, "XDG_CACHE_HOME", "HOME"] {
+        println!("cargo:rerun-if-env-changed={env_var}");
+    }
 
-fn main() {
     let out_dir_env = std::env::var("OUT_DIR").unwrap();
     let out_dir = Path::new(&out_dir_env).to_path_buf();
 
-    download_assets(&out_dir);
+    let platform = provide_assets(&out_dir)?;
     copy_dir_recursive(
-        &Path::new(CONFIG_PATH),
+        Path::new(CONFIG_PATH),
         &out_dir.join("assets/config")
-    ).unwrap();
+    ).map_err(|error| BuildError(format!("Cannot copy the simulator config from {CONFIG_PATH}: {error}")))?;
 
     let dest_path = Path::new(&out_dir).join("generated_code.rs");
-    let mut f = File::create(&dest_path).unwrap();
 
     // Write the generated Rust code to the file.
     let generated_code = format!(
         r#"
         pub const ASSETS_PATH: &str = "{}/assets";
+        pub const SIMULATOR_PLATFORM: Option<&str> = {:?};
         "#,
-        out_dir.to_str().unwrap()
+        out_dir.to_str().unwrap(),
+        platform.map(|platform| platform.name)
     );
 
-    f.write_all(generated_code.as_bytes()).unwrap()
+    write_bytes_to_file(&dest_path, generated_code.as_bytes())
+        .map_err(|error| BuildError(format!("Cannot write {}: {error}", dest_path.display())))
 }
 
-fn download_assets(out_dir: &Path) {
-    let version= std::env::var("CARGO_PKG_VERSION").unwrap();
-
-    let client = reqwest::blocking::Client::new();
-
-    #[cfg(all(target_os = "linux", target_arch = "x86_64"))]
-        let (chain_simulator_bin_name, libwasmer_name) = (
-            CHAIN_SIMULATOR_LINUX_AMD64_NAME,
-            LIBWASMER_LINUX_AMD64_NAME
-        );
-    #[cfg(all(target_os = "macos", any(target_arch = "x86_64", target_arch = "aarch64")))]
-        let (chain_simulator_bin_name, libwasmer_name) = (
-            CHAIN_SIMULATOR_DARWIN_AMD64_NAME,
-            LIBWASMER_DARWIN_AMD64_NAME
-        );
-    #[cfg(not(any(
-        all(target_os = "linux", target_arch = "x86_64"),
-        all(target_os = "macos", any(target_arch = "x86_64", target_arch = "aarch64"))
-    )))]
-        compile_error!("Unsupported arch!");
-
-    let files_to_download = vec![
-        (format!("assets/{chain_simulator_bin_name}"), format!("assets/{CHAIN_SIMULATOR_NAME}")),
-        (format!("assets/{libwasmer_name}"), format!("assets/{libwasmer_name}"))
-    ];
-
-
-    for (source_path, dest_path) in files_to_download {
-        let content = client
-            .get(
-                format!("https://github.com/gfusee/mx-chain-simulator-interface-rs/raw/{version}/{source_path}")
-            )
-            .send()
-            .unwrap()
-            .bytes()
-            .unwrap();
-
-        write_bytes_to_file(
-            Path::new(&out_dir.join(dest_path)),
-            &content
-        )
-            .unwrap()
+fn provide_assets(out_dir: &Path) -> Result<Option<&'static Platform>, BuildError> {
+    let version = std::env::var("CARGO_PKG_VERSION").unwrap();
+    let checksums = read_checksums_manifest()?;
+
+    // We read the target from cargo's env vars rather than cfg!, otherwise we would get the host when cross-compiling.
+    let target_os = std::env::var("CARGO_CFG_TARGET_OS").unwrap();
+    let target_arch = std::env::var("CARGO_CFG_TARGET_ARCH").unwrap();
+
+    let platform = PLATFORMS
+        .iter()
+        .find(|platform| platform.target_os == target_os && platform.target_arch == target_arch);
+
+    let local_assets_dir = std::env::var_os(ASSETS_DIR_ENV_VAR).map(PathBuf::from);
+    let local_bin = std::env::var_os(BIN_ENV_VAR).map(PathBuf::from);
+
+    let assets: Vec<Asset> = match platform {
+        Some(platform) => {
+            let mut assets = vec![Asset { source_name: platform.chain_simulator_name, dest_name: CHAIN_SIMULATOR_NAME }];
+            assets.extend(
+                platform.libraries
+                    .iter()
+                    .map(|library| Asset { source_name: library, dest_name: library })
+            );
+
+            assets
+        },
+        None if local_bin.is_some() || local_assets_dir.is_some() => {
+            vec![Asset { source_name: CHAIN_SIMULATOR_NAME, dest_name: CHAIN_SIMULATOR_NAME }]
+        },
+        None => {
+            println!(
+                "cargo:warning=No chain simulator binary is published for {target_os}/{target_arch}. Provide one through {BIN_ENV_VAR} at build time, or SimulatorOptions::with_binary_path at runtime."
+            );
+
+            return Ok(None);
+        }
+    };
+
+    let is_offline = std::env::var_os(OFFLINE_ENV_VAR).is_some();
+    let cache_dir = get_cache_dir(&version);
+
+    for asset in assets {
+        let dest_path = out_dir.join("assets").join(asset.dest_name);
+
+        let local_path = match (&local_bin, &local_assets_dir) {
+            (Some(bin), _) if asset.dest_name == CHAIN_SIMULATOR_NAME => Some(bin.clone()),
+            (_, Some(dir)) => Some(find_in_local_assets_dir(dir, &asset)?),
+            _ => None,
+        };
+
+        // Local files are provided by the user, they are trusted as-is and not checked against the manifest.
+        if let Some(local_path) = local_path {
+            println!("cargo:rerun-if-changed={}", local_path.display());
+            copy_file(&local_path, &dest_path)?;
+            continue;
+        }
+
+        let expected_checksum = checksums.iter()
+            .find(|(name, _)| name == asset.source_name)
+            .map(|(_, checksum)| checksum.as_str());
+
+        // The chainsimulator_* checksums are not pinned yet, the build would fail on every platform otherwise.
+        if expected_checksum.is_none() {
+            println!("cargo:warning=No checksum pinned in {CHECKSUMS_MANIFEST_PATH} for {}, it will not be verified.", asset.source_name);
+        }
+
+        let cached_path = cache_dir.as_ref().map(|dir| dir.join(asset.source_name));
+
+        if let Some(cached_path) = &cached_path {
+            if let Ok(content) = fs::read(cached_path) {
+                if verify_checksum(asset.source_name, &content, expected_checksum).is_ok() {
+                    write_asset(&dest_path, &content)?;
+                    continue;
+                }
+
+                println!("cargo:warning=Ignoring the corrupted cached file {}.", cached_path.display());
+            }
+        }
+
+        if is_offline {
+            return Err(BuildError(format!(
+                "{} is not available offline. Set {BIN_ENV_VAR} or {ASSETS_DIR_ENV_VAR} to use local binaries, or unset {OFFLINE_ENV_VAR}.",
+                asset.source_name
+            )));
+        }
+
+        let content = download_asset(&version, asset.source_name)?;
+        verify_checksum(asset.source_name, &content, expected_checksum)?;
+
+        if let Some(cached_path) = &cached_path {
+            // A cache write failure should never fail the build, the asset will be downloaded again next time.
+            if write_bytes_to_file(cached_path, &content).is_err() {
+                println!("cargo:warning=Cannot write {} to the cache.", cached_path.display());
+            }
+        }
+
+        write_asset(&dest_path, &content)?;
+    }
+
+    Ok(platform)
+}
+
+fn download_asset(version: &str, source_name: &str) -> Result<Vec<u8>, BuildError> {
+    let url = format!("https://github.com/gfusee/mx-chain-simulator-interface-rs/raw/{version}/assets/{source_name}");
+    let hint = format!("Set {BIN_ENV_VAR} or {ASSETS_DIR_ENV_VAR} to build without network access.");
+
+    let response = reqwest::blocking::Client::new()
+        .get(&url)
+        .send()
+        .map_err(|error| BuildError(format!("Cannot download {url}: {error}. {hint}")))?;
+
+    if !response.status().is_success() {
+        return Err(BuildError(format!("Cannot download {url}: status {}. {hint}", response.status())));
     }
 
+    let content = response
+        .bytes()
+        .map_err(|error| BuildError(format!("Cannot read the content of {url}: {error}. {hint}")))?;
 
+    Ok(content.to_vec())
+}
+
+fn find_in_local_assets_dir(dir: &Path, asset: &Asset) -> Result<PathBuf, BuildError> {
+    [asset.dest_name, asset.source_name]
+        .into_iter()
+        .map(|name| dir.join(name))
+        .find(|path| path.is_file())
+        .ok_or_else(|| BuildError(format!(
+            "Neither {} nor {} found in {} ({ASSETS_DIR_ENV_VAR}).",
+            asset.dest_name,
+            asset.source_name,
+            dir.display()
+        )))
+}
+
+fn get_cache_dir(version: &str) -> Option<PathBuf> {
+    let base_dir = match std::env::var_os(CACHE_DIR_ENV_VAR) {
+        Some(dir) => PathBuf::from(dir),
+        None => {
+            let cache_home = std::env::var_os("XDG_CACHE_HOME")
+                .map(PathBuf::from)
+                .or_else(|| std::env::var_os("HOME").map(|home| PathBuf::from(home).join(".cache")))?;
+
+            cache_home.join("mx-chain-simulator-interface-rs")
+        }
+    };
+
+    Some(base_dir.join(version))
+}
+
+fn read_checksums_manifest() -> Result<Vec<(String, String)>, BuildError> {
+    let content = fs::read_to_string(CHECKSUMS_MANIFEST_PATH)
+        .map_err(|error| BuildError(format!("Cannot read the checksums manifest {CHECKSUMS_MANIFEST_PATH}: {error}")))?;
+
+    let checksums = content
+        .lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty() && !line.starts_with('#'))
+        .filter_map(|line| {
+            let (checksum, name) = line.split_once(char::is_whitespace)?;
+            Some((name.trim().to_string(), checksum.to_lowercase()))
+        })
+        .collect();
+
+    Ok(checksums)
+}
+
+fn verify_checksum(name: &str, content: &[u8], expected_checksum: Option<&str>) -> Result<(), BuildError> {
+    let Some(expected_checksum) = expected_checksum else {
+        return Ok(());
+    };
+
+    let actual_checksum = hex::encode(Sha256::digest(content));
+
+    if actual_checksum != expected_checksum {
+        return Err(BuildError(format!(
+            "Checksum mismatch for {name}: expected {expected_checksum}, got {actual_checksum}."
+        )));
+    }
+
+    Ok(())
+}
+
+fn copy_file(src: &Path, dst: &Path) -> Result<(), BuildError> {
+    let content = fs::read(src)
+        .map_err(|error| BuildError(format!("Cannot read {}: {error}", src.display())))?;
+
+    write_asset(dst, &content)
+}
+
+fn write_asset(path: &Path, bytes: &[u8]) -> Result<(), BuildError> {
+    write_bytes_to_file(path, bytes)
+        .map_err(|error| BuildError(format!("Cannot write {}: {error}", path.display())))
 }
 
 fn write_bytes_to_file(path: &Path, bytes: &[u8]) -> io::Result<()> {
@@ -121,4 +324,4 @@ fn copy_dir_recursive(src: &Path, dst: &Path) -> io::Result<()> {
     }
 
     Ok(())
-}
\ No newline at end of file
+}
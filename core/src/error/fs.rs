@@ -2,10 +2,12 @@ use std::fmt::{Display, Formatter};
 use std::fs::Permissions;
 use crate::error::lib::LibError;
 
+#[allow(clippy::enum_variant_names)]
 #[derive(PartialEq, Debug, Clone)]
 pub enum FsError {
     CannotGetTempDir,
     CannotCopyAssets,
+    CannotFindAssets { searched_path: String },
     CannotCreateFile { file_path: String },
     CannotWriteBytesToFile { file_path: String, bytes: Vec<u8> },
     CannotSetPermissionsToFile { file_path: String, permissions: Permissions }
@@ -20,6 +22,9 @@ impl Display for FsError {
             FsError::CannotCopyAssets => {
                 write!(f, "Cannot copy assets")
             },
+            FsError::CannotFindAssets { searched_path } => {
+                write!(f, "Cannot find the chain simulator assets in {searched_path} nor in the user cache")
+            },
             FsError::CannotCreateFile { file_path } => {
               write!(f, "Cannot create a file at the specified path: {file_path}")
             },
@@ -1,21 +1,61 @@
 use std::fmt::{Display, Formatter};
 use crate::error::fs::FsError;
+use crate::error::node_config::NodeConfigError;
+use crate::error::nodes_setup::NodesSetupError;
+use crate::error::requests::about::AboutError;
+use crate::error::requests::address_nonce::AddressNonceError;
+use crate::error::requests::transaction_pool::TransactionPoolError;
+use crate::error::requests::add_validator_keys::AddValidatorKeysError;
+use crate::error::requests::block::BlockError;
 use crate::error::process::ProcessError;
+use crate::error::readiness::ReadinessError;
 use crate::error::requests::generate_blocks::GenerateBlocksError;
+use crate::error::requests::hyperblock::HyperblockError;
+use crate::error::requests::force_epoch_change::ForceEpochChangeError;
 use crate::error::requests::initial_wallets::InitialWalletsError;
+use crate::error::requests::network_config::NetworkConfigError;
+use crate::error::requests::network_economics::NetworkEconomicsError;
+use crate::error::requests::network_status::NetworkStatusError;
 use crate::error::requests::set_address_keys::SetAddressKeysError;
+use crate::error::requests::send_multiple_transactions::SendMultipleTransactionsError;
+use crate::error::requests::send_transaction::SendTransactionError;
 use crate::error::requests::set_state::SetStateError;
+use crate::error::requests::simulate_transaction::SimulateTransactionError;
+use crate::error::requests::transaction::TransactionError;
+use crate::error::requests::transaction_cost::TransactionCostError;
+use crate::error::requests::validator_statistics::ValidatorStatisticsError;
 use crate::error::simulator::SimulatorError;
+use crate::error::staking::StakingError;
 
 #[derive(PartialEq, Debug, Clone)]
 pub enum LibError {
     Fs(FsError),
     Process(ProcessError),
     Simulator(SimulatorError),
+    Readiness(ReadinessError),
+    NodeConfig(NodeConfigError),
+    NodesSetup(NodesSetupError),
     InitialWallets(InitialWalletsError),
     GenerateBlocks(GenerateBlocksError),
     SetAddressKeys(SetAddressKeysError),
     SetState(SetStateError),
+    NetworkStatus(NetworkStatusError),
+    NetworkConfig(NetworkConfigError),
+    ForceEpochChange(ForceEpochChangeError),
+    NetworkEconomics(NetworkEconomicsError),
+    About(AboutError),
+    Block(BlockError),
+    Hyperblock(HyperblockError),
+    Transaction(TransactionError),
+    SendTransaction(SendTransactionError),
+    TransactionCost(TransactionCostError),
+    SimulateTransaction(SimulateTransactionError),
+    AddressNonce(AddressNonceError),
+    TransactionPool(TransactionPoolError),
+    SendMultipleTransactions(SendMultipleTransactionsError),
+    Staking(StakingError),
+    AddValidatorKeys(AddValidatorKeysError),
+    ValidatorStatistics(ValidatorStatisticsError),
 }
 
 impl std::error::Error for LibError {}
@@ -32,6 +72,15 @@ impl Display for LibError {
             LibError::Simulator(error) => {
                 error.fmt(f)
             },
+            LibError::Readiness(error) => {
+                error.fmt(f)
+            },
+            LibError::NodeConfig(error) => {
+                error.fmt(f)
+            },
+            LibError::NodesSetup(error) => {
+                error.fmt(f)
+            },
             LibError::InitialWallets(error) => {
                 error.fmt(f)
             },
@@ -44,6 +93,57 @@ impl Display for LibError {
             LibError::SetState(error) => {
                 error.fmt(f)
             },
+            LibError::NetworkStatus(error) => {
+                error.fmt(f)
+            },
+            LibError::NetworkConfig(error) => {
+                error.fmt(f)
+            },
+            LibError::ForceEpochChange(error) => {
+                error.fmt(f)
+            },
+            LibError::NetworkEconomics(error) => {
+                error.fmt(f)
+            },
+            LibError::About(error) => {
+                error.fmt(f)
+            },
+            LibError::Block(error) => {
+                error.fmt(f)
+            },
+            LibError::Hyperblock(error) => {
+                error.fmt(f)
+            },
+            LibError::Transaction(error) => {
+                error.fmt(f)
+            },
+            LibError::SendTransaction(error) => {
+                error.fmt(f)
+            },
+            LibError::TransactionCost(error) => {
+                error.fmt(f)
+            },
+            LibError::SimulateTransaction(error) => {
+                error.fmt(f)
+            },
+            LibError::AddressNonce(error) => {
+                error.fmt(f)
+            },
+            LibError::TransactionPool(error) => {
+                error.fmt(f)
+            },
+            LibError::SendMultipleTransactions(error) => {
+                error.fmt(f)
+            },
+            LibError::Staking(error) => {
+                error.fmt(f)
+            },
+            LibError::AddValidatorKeys(error) => {
+                error.fmt(f)
+            },
+            LibError::ValidatorStatistics(error) => {
+                error.fmt(f)
+            },
         }
     }
 }
\ No newline at end of file
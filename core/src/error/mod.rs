@@ -1,5 +1,9 @@
 pub mod fs;
 pub mod lib;
+pub mod node_config;
+pub mod nodes_setup;
 pub mod process;
+pub mod readiness;
 pub mod simulator;
+pub mod staking;
 pub mod requests;
\ No newline at end of file
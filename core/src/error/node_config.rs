@@ -0,0 +1,40 @@
+use std::fmt::{Display, Formatter};
+use crate::error::lib::LibError;
+
+#[allow(clippy::enum_variant_names)]
+#[derive(PartialEq, Debug, Clone)]
+pub enum NodeConfigError {
+    CannotReadFile { file_path: String },
+    CannotParseFile { file_path: String },
+    CannotWriteFile { file_path: String },
+    CannotFindSection { file_path: String, section: String },
+    CannotFindRoute { file_path: String, package: String, route: String },
+}
+
+impl Display for NodeConfigError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            NodeConfigError::CannotReadFile { file_path } => {
+                write!(f, "Cannot read the config file {file_path}")
+            },
+            NodeConfigError::CannotParseFile { file_path } => {
+                write!(f, "Cannot parse the config file {file_path} as TOML")
+            },
+            NodeConfigError::CannotWriteFile { file_path } => {
+                write!(f, "Cannot write the config file {file_path}")
+            },
+            NodeConfigError::CannotFindSection { file_path, section } => {
+                write!(f, "Cannot find the [{section}] section in the config file {file_path}")
+            },
+            NodeConfigError::CannotFindRoute { file_path, package, route } => {
+                write!(f, "Cannot find the route \"{route}\" of the {package} API package in the config file {file_path}")
+            },
+        }
+    }
+}
+
+impl From<NodeConfigError> for LibError {
+    fn from(value: NodeConfigError) -> Self {
+        LibError::NodeConfig(value)
+    }
+}
@@ -0,0 +1,36 @@
+use std::fmt::{Display, Formatter};
+use crate::error::lib::LibError;
+use crate::simulator::shard::ShardId;
+
+#[derive(PartialEq, Debug, Clone)]
+pub enum NodesSetupError {
+    NoShards,
+    ZeroNodes { shard: ShardId },
+    NotEnoughValidators { shard: ShardId, min_nodes: u32, num_of_validators: u32 },
+    InvalidConsensusGroupSize { shard: ShardId, consensus_group_size: u32, max_consensus_group_size: u32 },
+}
+
+impl Display for NodesSetupError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            NodesSetupError::NoShards => {
+                write!(f, "The simulator needs at least one shard besides the metachain.")
+            },
+            NodesSetupError::ZeroNodes { shard } => {
+                write!(f, "The validators, min nodes and consensus group size of shard {shard} can't be zero.")
+            },
+            NodesSetupError::NotEnoughValidators { shard, min_nodes, num_of_validators } => {
+                write!(f, "Shard {shard} has {num_of_validators} validators while it needs at least {min_nodes}.")
+            },
+            NodesSetupError::InvalidConsensusGroupSize { shard, consensus_group_size, max_consensus_group_size } => {
+                write!(f, "The consensus group size of shard {shard} is {consensus_group_size}, it can't be larger than {max_consensus_group_size}.")
+            },
+        }
+    }
+}
+
+impl From<NodesSetupError> for LibError {
+    fn from(value: NodesSetupError) -> Self {
+        LibError::NodesSetup(value)
+    }
+}
@@ -5,6 +5,8 @@ use crate::error::lib::LibError;
 pub enum ProcessError {
     CannotSpawnProcess,
     CannotCopyAssets,
+    SimulatorBinaryNotFound,
+    CannotStopContainer { name: String },
     UnsupportedOSAndArch { os: String, arch: String }
 }
 
@@ -17,8 +19,14 @@ impl Display for ProcessError {
             ProcessError::CannotCopyAssets => {
                 write!(f, "Cannot copy chain simulator assets")
             },
+            ProcessError::SimulatorBinaryNotFound => {
+                write!(f, "Cannot find the chain simulator binary. Set it using SimulatorOptions::with_binary_path, the MX_CHAIN_SIMULATOR_BIN environment variable or add it to $PATH")
+            },
+            ProcessError::CannotStopContainer { name } => {
+                write!(f, "Cannot stop the chain simulator container {name}")
+            },
             ProcessError::UnsupportedOSAndArch { os, arch } => {
-                write!(f, "Unsupported OS and arch: {os} {arch}. Supported OS and arch: linux/amd64, darwin/amd64")
+                write!(f, "Unsupported OS and arch: {os} {arch}. Chain simulator binaries are only published for linux/amd64 and darwin/amd64. Other platforms require a binary set using SimulatorOptions::with_binary_path")
             },
         }
     }
@@ -0,0 +1,39 @@
+use std::fmt::{Display, Formatter};
+use std::time::Duration;
+use crate::error::lib::LibError;
+
+#[derive(PartialEq, Debug, Clone)]
+pub enum ReadinessError {
+    TimedOut { timeout: Duration, last_unready_url: Option<String> },
+    ProcessExitedDuringStartup { code: Option<i32>, signal: Option<i32>, last_log_lines: Vec<String> },
+    ShardsCountMismatch { expected: u64, actual: u64 },
+    InitialEpochNotReached { expected: u64, actual: u64 },
+}
+
+impl Display for ReadinessError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ReadinessError::TimedOut { timeout, last_unready_url } => {
+                match last_unready_url {
+                    Some(url) => write!(f, "Timed out after {timeout:?} while waiting for the simulator to be ready. Last unready url: {url}"),
+                    None => write!(f, "Timed out after {timeout:?} while waiting for the simulator to be ready."),
+                }
+            },
+            ReadinessError::ProcessExitedDuringStartup { code, signal, last_log_lines } => {
+                write!(f, "Simulator exited during startup with code {code:?} and signal {signal:?}. Last log lines:\n{}", last_log_lines.join("\n"))
+            },
+            ReadinessError::ShardsCountMismatch { expected, actual } => {
+                write!(f, "The simulator runs {actual} shards while {expected} were requested.")
+            },
+            ReadinessError::InitialEpochNotReached { expected, actual } => {
+                write!(f, "The simulator is at epoch {actual} while epoch {expected} was expected after startup.")
+            },
+        }
+    }
+}
+
+impl From<ReadinessError> for LibError {
+    fn from(value: ReadinessError) -> Self {
+        LibError::Readiness(value)
+    }
+}
@@ -0,0 +1,39 @@
+use std::fmt::{Display, Formatter};
+use crate::error::lib::LibError;
+
+#[derive(PartialEq, Debug, Clone)]
+pub enum AboutError {
+    CannotSendRequest { url: String },
+    ResponseStatusIsNotSuccessful { url: String, status: u16 },
+    CannotGetTextFromTheResponse { url: String },
+    FailedToParseTheResponse { url: String, response: String },
+    ResponseCodeIsNotSuccessful { url: String, code: String },
+}
+
+impl Display for AboutError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            AboutError::CannotSendRequest { url } => {
+                write!(f, "Cannot send the about request. Url: {url}")
+            },
+            AboutError::ResponseStatusIsNotSuccessful { url, status } => {
+                write!(f, "About response's status is not successful: {status}, url: {url}")
+            },
+            AboutError::CannotGetTextFromTheResponse  { url } => {
+                write!(f, "No text received in the about response. Url: {url}")
+            },
+            AboutError::FailedToParseTheResponse { url, response } => {
+                write!(f, "Cannot parse the received about response: {response}, url: {url}")
+            },
+            AboutError::ResponseCodeIsNotSuccessful { url, code } => {
+                write!(f, "About response's code is not successful: {code}, url: {url}")
+            },
+        }
+    }
+}
+
+impl From<AboutError> for LibError {
+    fn from(value: AboutError) -> Self {
+        LibError::About(value)
+    }
+}
\ No newline at end of file
@@ -0,0 +1,55 @@
+use std::fmt::{Display, Formatter};
+use crate::error::lib::LibError;
+
+#[derive(PartialEq, Debug, Clone)]
+pub enum AddValidatorKeysError {
+    NoKeys,
+    InvalidPem,
+    CannotSendRequest { url: String },
+    ResponseStatusIsNotSuccessful { url: String, status: u16 },
+    CannotGetTextFromTheResponse { url: String },
+    FailedToParseTheResponse { url: String, response: String },
+    ResponseCodeIsNotSuccessful { url: String, code: String },
+    KeysRejected { url: String, error: String },
+    CannotConvertKeysAsJSON { url: String },
+}
+
+impl Display for AddValidatorKeysError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            AddValidatorKeysError::NoKeys => {
+                write!(f, "At least one validator key is needed.")
+            },
+            AddValidatorKeysError::InvalidPem => {
+                write!(f, "The validator keys are not a valid .pem content, or one of its keys doesn't match the public key it is labelled with.")
+            },
+            AddValidatorKeysError::CannotSendRequest { url } => {
+                write!(f, "Cannot send the add validator keys request. Url: {url}")
+            },
+            AddValidatorKeysError::ResponseStatusIsNotSuccessful { url, status } => {
+                write!(f, "Add validator keys response's status is not successful: {status}, url: {url}")
+            },
+            AddValidatorKeysError::CannotGetTextFromTheResponse  { url } => {
+                write!(f, "No text received in the add validator keys response. Url: {url}")
+            },
+            AddValidatorKeysError::FailedToParseTheResponse { url, response } => {
+                write!(f, "Cannot parse the received add validator keys response: {response}, url: {url}")
+            },
+            AddValidatorKeysError::ResponseCodeIsNotSuccessful { url, code } => {
+                write!(f, "Add validator keys response's code is not successful: {code}, url: {url}")
+            },
+            AddValidatorKeysError::KeysRejected { url, error } => {
+                write!(f, "The simulator rejected the validator keys: {error}, url: {url}")
+            },
+            AddValidatorKeysError::CannotConvertKeysAsJSON { url } => {
+                write!(f, "Error while creating the add validator keys body for POST {url}")
+            },
+        }
+    }
+}
+
+impl From<AddValidatorKeysError> for LibError {
+    fn from(value: AddValidatorKeysError) -> Self {
+        LibError::AddValidatorKeys(value)
+    }
+}
@@ -0,0 +1,39 @@
+use std::fmt::{Display, Formatter};
+use crate::error::lib::LibError;
+
+#[derive(PartialEq, Debug, Clone)]
+pub enum AddressNonceError {
+    CannotSendRequest { url: String },
+    ResponseStatusIsNotSuccessful { url: String, status: u16 },
+    CannotGetTextFromTheResponse { url: String },
+    FailedToParseTheResponse { url: String, response: String },
+    ResponseCodeIsNotSuccessful { url: String, code: String },
+}
+
+impl Display for AddressNonceError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            AddressNonceError::CannotSendRequest { url } => {
+                write!(f, "Cannot send the address nonce request. Url: {url}")
+            },
+            AddressNonceError::ResponseStatusIsNotSuccessful { url, status } => {
+                write!(f, "Address nonce response's status is not successful: {status}, url: {url}")
+            },
+            AddressNonceError::CannotGetTextFromTheResponse  { url } => {
+                write!(f, "No text received in the address nonce response. Url: {url}")
+            },
+            AddressNonceError::FailedToParseTheResponse { url, response } => {
+                write!(f, "Cannot parse the received address nonce response: {response}, url: {url}")
+            },
+            AddressNonceError::ResponseCodeIsNotSuccessful { url, code } => {
+                write!(f, "Address nonce response's code is not successful: {code}, url: {url}")
+            },
+        }
+    }
+}
+
+impl From<AddressNonceError> for LibError {
+    fn from(value: AddressNonceError) -> Self {
+        LibError::AddressNonce(value)
+    }
+}
\ No newline at end of file
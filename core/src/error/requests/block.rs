@@ -0,0 +1,39 @@
+use std::fmt::{Display, Formatter};
+use crate::error::lib::LibError;
+
+#[derive(PartialEq, Debug, Clone)]
+pub enum BlockError {
+    CannotSendRequest { url: String },
+    ResponseStatusIsNotSuccessful { url: String, status: u16 },
+    CannotGetTextFromTheResponse { url: String },
+    FailedToParseTheResponse { url: String, response: String },
+    ResponseCodeIsNotSuccessful { url: String, code: String },
+}
+
+impl Display for BlockError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            BlockError::CannotSendRequest { url } => {
+                write!(f, "Cannot send the block request. Url: {url}")
+            },
+            BlockError::ResponseStatusIsNotSuccessful { url, status } => {
+                write!(f, "Block response's status is not successful: {status}, url: {url}")
+            },
+            BlockError::CannotGetTextFromTheResponse  { url } => {
+                write!(f, "No text received in the block response. Url: {url}")
+            },
+            BlockError::FailedToParseTheResponse { url, response } => {
+                write!(f, "Cannot parse the received block response: {response}, url: {url}")
+            },
+            BlockError::ResponseCodeIsNotSuccessful { url, code } => {
+                write!(f, "Block response's code is not successful: {code}, url: {url}")
+            },
+        }
+    }
+}
+
+impl From<BlockError> for LibError {
+    fn from(value: BlockError) -> Self {
+        LibError::Block(value)
+    }
+}
\ No newline at end of file
@@ -0,0 +1,39 @@
+use std::fmt::{Display, Formatter};
+use crate::error::lib::LibError;
+
+#[derive(PartialEq, Debug, Clone)]
+pub enum ForceEpochChangeError {
+    CannotSendRequest { url: String },
+    ResponseStatusIsNotSuccessful { url: String, status: u16 },
+    CannotGetTextFromTheResponse { url: String },
+    FailedToParseTheResponse { url: String, response: String },
+    ResponseCodeIsNotSuccessful { url: String, code: String },
+}
+
+impl Display for ForceEpochChangeError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ForceEpochChangeError::CannotSendRequest { url } => {
+                write!(f, "Cannot send the force epoch change request. Url: {url}")
+            },
+            ForceEpochChangeError::ResponseStatusIsNotSuccessful { url, status } => {
+                write!(f, "Force epoch change response's status is not successful: {status}, url: {url}")
+            },
+            ForceEpochChangeError::CannotGetTextFromTheResponse  { url } => {
+                write!(f, "No text received in the force epoch change response. Url: {url}")
+            },
+            ForceEpochChangeError::FailedToParseTheResponse { url, response } => {
+                write!(f, "Cannot parse the received force epoch change response: {response}, url: {url}")
+            },
+            ForceEpochChangeError::ResponseCodeIsNotSuccessful { url, code } => {
+                write!(f, "Force epoch change response's code is not successful: {code}, url: {url}")
+            },
+        }
+    }
+}
+
+impl From<ForceEpochChangeError> for LibError {
+    fn from(value: ForceEpochChangeError) -> Self {
+        LibError::ForceEpochChange(value)
+    }
+}
\ No newline at end of file
@@ -0,0 +1,39 @@
+use std::fmt::{Display, Formatter};
+use crate::error::lib::LibError;
+
+#[derive(PartialEq, Debug, Clone)]
+pub enum HyperblockError {
+    CannotSendRequest { url: String },
+    ResponseStatusIsNotSuccessful { url: String, status: u16 },
+    CannotGetTextFromTheResponse { url: String },
+    FailedToParseTheResponse { url: String, response: String },
+    ResponseCodeIsNotSuccessful { url: String, code: String },
+}
+
+impl Display for HyperblockError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            HyperblockError::CannotSendRequest { url } => {
+                write!(f, "Cannot send the hyperblock request. Url: {url}")
+            },
+            HyperblockError::ResponseStatusIsNotSuccessful { url, status } => {
+                write!(f, "Hyperblock response's status is not successful: {status}, url: {url}")
+            },
+            HyperblockError::CannotGetTextFromTheResponse  { url } => {
+                write!(f, "No text received in the hyperblock response. Url: {url}")
+            },
+            HyperblockError::FailedToParseTheResponse { url, response } => {
+                write!(f, "Cannot parse the received hyperblock response: {response}, url: {url}")
+            },
+            HyperblockError::ResponseCodeIsNotSuccessful { url, code } => {
+                write!(f, "Hyperblock response's code is not successful: {code}, url: {url}")
+            },
+        }
+    }
+}
+
+impl From<HyperblockError> for LibError {
+    fn from(value: HyperblockError) -> Self {
+        LibError::Hyperblock(value)
+    }
+}
\ No newline at end of file
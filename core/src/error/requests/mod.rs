@@ -1,4 +1,20 @@
 pub mod generate_blocks;
 pub mod set_address_keys;
 pub mod set_state;
-pub mod initial_wallets;
\ No newline at end of file
+pub mod initial_wallets;
+pub mod network_status;
+pub mod network_config;
+pub mod force_epoch_change;
+pub mod network_economics;
+pub mod about;
+pub mod block;
+pub mod hyperblock;
+pub mod transaction;
+pub mod send_transaction;
+pub mod send_multiple_transactions;
+pub mod transaction_cost;
+pub mod simulate_transaction;
+pub mod address_nonce;
+pub mod add_validator_keys;
+pub mod validator_statistics;
+pub mod transaction_pool;
@@ -0,0 +1,39 @@
+use std::fmt::{Display, Formatter};
+use crate::error::lib::LibError;
+
+#[derive(PartialEq, Debug, Clone)]
+pub enum NetworkConfigError {
+    CannotSendRequest { url: String },
+    ResponseStatusIsNotSuccessful { url: String, status: u16 },
+    CannotGetTextFromTheResponse { url: String },
+    FailedToParseTheResponse { url: String, response: String },
+    ResponseCodeIsNotSuccessful { url: String, code: String },
+}
+
+impl Display for NetworkConfigError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            NetworkConfigError::CannotSendRequest { url } => {
+                write!(f, "Cannot send the network config request. Url: {url}")
+            },
+            NetworkConfigError::ResponseStatusIsNotSuccessful { url, status } => {
+                write!(f, "Network config response's status is not successful: {status}, url: {url}")
+            },
+            NetworkConfigError::CannotGetTextFromTheResponse  { url } => {
+                write!(f, "No text received in the network config response. Url: {url}")
+            },
+            NetworkConfigError::FailedToParseTheResponse { url, response } => {
+                write!(f, "Cannot parse the received network config response: {response}, url: {url}")
+            },
+            NetworkConfigError::ResponseCodeIsNotSuccessful { url, code } => {
+                write!(f, "Network config response's code is not successful: {code}, url: {url}")
+            },
+        }
+    }
+}
+
+impl From<NetworkConfigError> for LibError {
+    fn from(value: NetworkConfigError) -> Self {
+        LibError::NetworkConfig(value)
+    }
+}
\ No newline at end of file
@@ -0,0 +1,39 @@
+use std::fmt::{Display, Formatter};
+use crate::error::lib::LibError;
+
+#[derive(PartialEq, Debug, Clone)]
+pub enum NetworkEconomicsError {
+    CannotSendRequest { url: String },
+    ResponseStatusIsNotSuccessful { url: String, status: u16 },
+    CannotGetTextFromTheResponse { url: String },
+    FailedToParseTheResponse { url: String, response: String },
+    ResponseCodeIsNotSuccessful { url: String, code: String },
+}
+
+impl Display for NetworkEconomicsError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            NetworkEconomicsError::CannotSendRequest { url } => {
+                write!(f, "Cannot send the network economics request. Url: {url}")
+            },
+            NetworkEconomicsError::ResponseStatusIsNotSuccessful { url, status } => {
+                write!(f, "Network economics response's status is not successful: {status}, url: {url}")
+            },
+            NetworkEconomicsError::CannotGetTextFromTheResponse  { url } => {
+                write!(f, "No text received in the network economics response. Url: {url}")
+            },
+            NetworkEconomicsError::FailedToParseTheResponse { url, response } => {
+                write!(f, "Cannot parse the received network economics response: {response}, url: {url}")
+            },
+            NetworkEconomicsError::ResponseCodeIsNotSuccessful { url, code } => {
+                write!(f, "Network economics response's code is not successful: {code}, url: {url}")
+            },
+        }
+    }
+}
+
+impl From<NetworkEconomicsError> for LibError {
+    fn from(value: NetworkEconomicsError) -> Self {
+        LibError::NetworkEconomics(value)
+    }
+}
\ No newline at end of file
@@ -0,0 +1,39 @@
+use std::fmt::{Display, Formatter};
+use crate::error::lib::LibError;
+
+#[derive(PartialEq, Debug, Clone)]
+pub enum NetworkStatusError {
+    CannotSendRequest { url: String },
+    ResponseStatusIsNotSuccessful { url: String, status: u16 },
+    CannotGetTextFromTheResponse { url: String },
+    FailedToParseTheResponse { url: String, response: String },
+    ResponseCodeIsNotSuccessful { url: String, code: String },
+}
+
+impl Display for NetworkStatusError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            NetworkStatusError::CannotSendRequest { url } => {
+                write!(f, "Cannot send the network status request. Url: {url}")
+            },
+            NetworkStatusError::ResponseStatusIsNotSuccessful { url, status } => {
+                write!(f, "Network status response's status is not successful: {status}, url: {url}")
+            },
+            NetworkStatusError::CannotGetTextFromTheResponse  { url } => {
+                write!(f, "No text received in the network status response. Url: {url}")
+            },
+            NetworkStatusError::FailedToParseTheResponse { url, response } => {
+                write!(f, "Cannot parse the received network status response: {response}, url: {url}")
+            },
+            NetworkStatusError::ResponseCodeIsNotSuccessful { url, code } => {
+                write!(f, "Network status response's code is not successful: {code}, url: {url}")
+            },
+        }
+    }
+}
+
+impl From<NetworkStatusError> for LibError {
+    fn from(value: NetworkStatusError) -> Self {
+        LibError::NetworkStatus(value)
+    }
+}
\ No newline at end of file
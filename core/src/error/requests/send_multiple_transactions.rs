@@ -0,0 +1,43 @@
+use std::fmt::{Display, Formatter};
+use crate::error::lib::LibError;
+
+#[derive(PartialEq, Debug, Clone)]
+pub enum SendMultipleTransactionsError {
+    CannotConvertTransactionsAsJSON { url: String },
+    CannotSendRequest { url: String },
+    ResponseStatusIsNotSuccessful { url: String, status: u16 },
+    CannotGetTextFromTheResponse { url: String },
+    FailedToParseTheResponse { url: String, response: String },
+    ResponseCodeIsNotSuccessful { url: String, code: String },
+}
+
+impl Display for SendMultipleTransactionsError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            SendMultipleTransactionsError::CannotConvertTransactionsAsJSON { url } => {
+                write!(f, "Cannot convert the transactions to JSON for the send multiple transactions request. Url: {url}")
+            },
+            SendMultipleTransactionsError::CannotSendRequest { url } => {
+                write!(f, "Cannot send the send multiple transactions request. Url: {url}")
+            },
+            SendMultipleTransactionsError::ResponseStatusIsNotSuccessful { url, status } => {
+                write!(f, "Send multiple transactions response's status is not successful: {status}, url: {url}")
+            },
+            SendMultipleTransactionsError::CannotGetTextFromTheResponse  { url } => {
+                write!(f, "No text received in the send multiple transactions response. Url: {url}")
+            },
+            SendMultipleTransactionsError::FailedToParseTheResponse { url, response } => {
+                write!(f, "Cannot parse the received send multiple transactions response: {response}, url: {url}")
+            },
+            SendMultipleTransactionsError::ResponseCodeIsNotSuccessful { url, code } => {
+                write!(f, "Send multiple transactions response's code is not successful: {code}, url: {url}")
+            },
+        }
+    }
+}
+
+impl From<SendMultipleTransactionsError> for LibError {
+    fn from(value: SendMultipleTransactionsError) -> Self {
+        LibError::SendMultipleTransactions(value)
+    }
+}
\ No newline at end of file
@@ -0,0 +1,47 @@
+use std::fmt::{Display, Formatter};
+use crate::error::lib::LibError;
+
+#[derive(PartialEq, Debug, Clone)]
+pub enum SendTransactionError {
+    CannotConvertTransactionAsJSON { url: String },
+    CannotSendRequest { url: String },
+    ResponseStatusIsNotSuccessful { url: String, status: u16 },
+    CannotGetTextFromTheResponse { url: String },
+    FailedToParseTheResponse { url: String, response: String },
+    ResponseCodeIsNotSuccessful { url: String, code: String },
+    TransactionRejected { url: String, error: String },
+}
+
+impl Display for SendTransactionError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            SendTransactionError::CannotConvertTransactionAsJSON { url } => {
+                write!(f, "Cannot convert the transaction to JSON for the send transaction request. Url: {url}")
+            },
+            SendTransactionError::CannotSendRequest { url } => {
+                write!(f, "Cannot send the send transaction request. Url: {url}")
+            },
+            SendTransactionError::ResponseStatusIsNotSuccessful { url, status } => {
+                write!(f, "Send transaction response's status is not successful: {status}, url: {url}")
+            },
+            SendTransactionError::CannotGetTextFromTheResponse  { url } => {
+                write!(f, "No text received in the send transaction response. Url: {url}")
+            },
+            SendTransactionError::FailedToParseTheResponse { url, response } => {
+                write!(f, "Cannot parse the received send transaction response: {response}, url: {url}")
+            },
+            SendTransactionError::ResponseCodeIsNotSuccessful { url, code } => {
+                write!(f, "Send transaction response's code is not successful: {code}, url: {url}")
+            },
+            SendTransactionError::TransactionRejected { url, error } => {
+                write!(f, "The transaction has been rejected: {error}, url: {url}")
+            },
+        }
+    }
+}
+
+impl From<SendTransactionError> for LibError {
+    fn from(value: SendTransactionError) -> Self {
+        LibError::SendTransaction(value)
+    }
+}
\ No newline at end of file
@@ -0,0 +1,43 @@
+use std::fmt::{Display, Formatter};
+use crate::error::lib::LibError;
+
+#[derive(PartialEq, Debug, Clone)]
+pub enum SimulateTransactionError {
+    CannotConvertTransactionAsJSON { url: String },
+    CannotSendRequest { url: String },
+    ResponseStatusIsNotSuccessful { url: String, status: u16 },
+    CannotGetTextFromTheResponse { url: String },
+    FailedToParseTheResponse { url: String, response: String },
+    ResponseCodeIsNotSuccessful { url: String, code: String },
+}
+
+impl Display for SimulateTransactionError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            SimulateTransactionError::CannotConvertTransactionAsJSON { url } => {
+                write!(f, "Cannot convert the transaction to JSON for the simulate transaction request. Url: {url}")
+            },
+            SimulateTransactionError::CannotSendRequest { url } => {
+                write!(f, "Cannot send the simulate transaction request. Url: {url}")
+            },
+            SimulateTransactionError::ResponseStatusIsNotSuccessful { url, status } => {
+                write!(f, "Simulate transaction response's status is not successful: {status}, url: {url}")
+            },
+            SimulateTransactionError::CannotGetTextFromTheResponse  { url } => {
+                write!(f, "No text received in the simulate transaction response. Url: {url}")
+            },
+            SimulateTransactionError::FailedToParseTheResponse { url, response } => {
+                write!(f, "Cannot parse the received simulate transaction response: {response}, url: {url}")
+            },
+            SimulateTransactionError::ResponseCodeIsNotSuccessful { url, code } => {
+                write!(f, "Simulate transaction response's code is not successful: {code}, url: {url}")
+            },
+        }
+    }
+}
+
+impl From<SimulateTransactionError> for LibError {
+    fn from(value: SimulateTransactionError) -> Self {
+        LibError::SimulateTransaction(value)
+    }
+}
\ No newline at end of file
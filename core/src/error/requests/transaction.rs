@@ -0,0 +1,39 @@
+use std::fmt::{Display, Formatter};
+use crate::error::lib::LibError;
+
+#[derive(PartialEq, Debug, Clone)]
+pub enum TransactionError {
+    CannotSendRequest { url: String },
+    ResponseStatusIsNotSuccessful { url: String, status: u16 },
+    CannotGetTextFromTheResponse { url: String },
+    FailedToParseTheResponse { url: String, response: String },
+    ResponseCodeIsNotSuccessful { url: String, code: String },
+}
+
+impl Display for TransactionError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            TransactionError::CannotSendRequest { url } => {
+                write!(f, "Cannot send the transaction request. Url: {url}")
+            },
+            TransactionError::ResponseStatusIsNotSuccessful { url, status } => {
+                write!(f, "Transaction response's status is not successful: {status}, url: {url}")
+            },
+            TransactionError::CannotGetTextFromTheResponse  { url } => {
+                write!(f, "No text received in the transaction response. Url: {url}")
+            },
+            TransactionError::FailedToParseTheResponse { url, response } => {
+                write!(f, "Cannot parse the received transaction response: {response}, url: {url}")
+            },
+            TransactionError::ResponseCodeIsNotSuccessful { url, code } => {
+                write!(f, "Transaction response's code is not successful: {code}, url: {url}")
+            },
+        }
+    }
+}
+
+impl From<TransactionError> for LibError {
+    fn from(value: TransactionError) -> Self {
+        LibError::Transaction(value)
+    }
+}
\ No newline at end of file
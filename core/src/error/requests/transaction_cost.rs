@@ -0,0 +1,47 @@
+use std::fmt::{Display, Formatter};
+use crate::error::lib::LibError;
+
+#[derive(PartialEq, Debug, Clone)]
+pub enum TransactionCostError {
+    CannotConvertTransactionAsJSON { url: String },
+    CannotSendRequest { url: String },
+    ResponseStatusIsNotSuccessful { url: String, status: u16 },
+    CannotGetTextFromTheResponse { url: String },
+    FailedToParseTheResponse { url: String, response: String },
+    ResponseCodeIsNotSuccessful { url: String, code: String },
+    EstimationFailed { url: String, return_message: String },
+}
+
+impl Display for TransactionCostError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            TransactionCostError::CannotConvertTransactionAsJSON { url } => {
+                write!(f, "Cannot convert the transaction to JSON for the transaction cost request. Url: {url}")
+            },
+            TransactionCostError::CannotSendRequest { url } => {
+                write!(f, "Cannot send the transaction cost request. Url: {url}")
+            },
+            TransactionCostError::ResponseStatusIsNotSuccessful { url, status } => {
+                write!(f, "Transaction cost response's status is not successful: {status}, url: {url}")
+            },
+            TransactionCostError::CannotGetTextFromTheResponse  { url } => {
+                write!(f, "No text received in the transaction cost response. Url: {url}")
+            },
+            TransactionCostError::FailedToParseTheResponse { url, response } => {
+                write!(f, "Cannot parse the received transaction cost response: {response}, url: {url}")
+            },
+            TransactionCostError::ResponseCodeIsNotSuccessful { url, code } => {
+                write!(f, "Transaction cost response's code is not successful: {code}, url: {url}")
+            },
+            TransactionCostError::EstimationFailed { url, return_message } => {
+                write!(f, "Cannot estimate the transaction cost: {return_message}, url: {url}")
+            },
+        }
+    }
+}
+
+impl From<TransactionCostError> for LibError {
+    fn from(value: TransactionCostError) -> Self {
+        LibError::TransactionCost(value)
+    }
+}
\ No newline at end of file
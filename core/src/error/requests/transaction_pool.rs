@@ -0,0 +1,39 @@
+use std::fmt::{Display, Formatter};
+use crate::error::lib::LibError;
+
+#[derive(PartialEq, Debug, Clone)]
+pub enum TransactionPoolError {
+    CannotSendRequest { url: String },
+    ResponseStatusIsNotSuccessful { url: String, status: u16 },
+    CannotGetTextFromTheResponse { url: String },
+    FailedToParseTheResponse { url: String, response: String },
+    ResponseCodeIsNotSuccessful { url: String, code: String },
+}
+
+impl Display for TransactionPoolError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            TransactionPoolError::CannotSendRequest { url } => {
+                write!(f, "Cannot send the transaction pool request. Url: {url}")
+            },
+            TransactionPoolError::ResponseStatusIsNotSuccessful { url, status } => {
+                write!(f, "Address nonce response's status is not successful: {status}, url: {url}")
+            },
+            TransactionPoolError::CannotGetTextFromTheResponse  { url } => {
+                write!(f, "No text received in the transaction pool response. Url: {url}")
+            },
+            TransactionPoolError::FailedToParseTheResponse { url, response } => {
+                write!(f, "Cannot parse the received transaction pool response: {response}, url: {url}")
+            },
+            TransactionPoolError::ResponseCodeIsNotSuccessful { url, code } => {
+                write!(f, "Address nonce response's code is not successful: {code}, url: {url}")
+            },
+        }
+    }
+}
+
+impl From<TransactionPoolError> for LibError {
+    fn from(value: TransactionPoolError) -> Self {
+        LibError::TransactionPool(value)
+    }
+}
\ No newline at end of file
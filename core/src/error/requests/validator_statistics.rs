@@ -0,0 +1,43 @@
+use std::fmt::{Display, Formatter};
+use crate::error::lib::LibError;
+
+#[derive(PartialEq, Debug, Clone)]
+pub enum ValidatorStatisticsError {
+    CannotSendRequest { url: String },
+    ResponseStatusIsNotSuccessful { url: String, status: u16 },
+    CannotGetTextFromTheResponse { url: String },
+    FailedToParseTheResponse { url: String, response: String },
+    ResponseCodeIsNotSuccessful { url: String, code: String },
+    MissingData { url: String },
+}
+
+impl Display for ValidatorStatisticsError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ValidatorStatisticsError::CannotSendRequest { url } => {
+                write!(f, "Cannot send the validator statistics request. Url: {url}")
+            },
+            ValidatorStatisticsError::ResponseStatusIsNotSuccessful { url, status } => {
+                write!(f, "Validator statistics response's status is not successful: {status}, url: {url}")
+            },
+            ValidatorStatisticsError::CannotGetTextFromTheResponse  { url } => {
+                write!(f, "No text received in the validator statistics response. Url: {url}")
+            },
+            ValidatorStatisticsError::FailedToParseTheResponse { url, response } => {
+                write!(f, "Cannot parse the received validator statistics response: {response}, url: {url}")
+            },
+            ValidatorStatisticsError::ResponseCodeIsNotSuccessful { url, code } => {
+                write!(f, "Validator statistics response's code is not successful: {code}, url: {url}")
+            },
+            ValidatorStatisticsError::MissingData { url } => {
+                write!(f, "Validator statistics response holds no data. Url: {url}")
+            },
+        }
+    }
+}
+
+impl From<ValidatorStatisticsError> for LibError {
+    fn from(value: ValidatorStatisticsError) -> Self {
+        LibError::ValidatorStatistics(value)
+    }
+}
\ No newline at end of file
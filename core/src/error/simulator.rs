@@ -4,12 +4,24 @@ use crate::error::lib::LibError;
 #[derive(PartialEq, Debug, Clone)]
 pub enum SimulatorError {
     CannotKillProcess,
-    TimedOutWhileWaitingToBeReady,
     ProcessNotStarted,
-    StdoutAlreadyConsumed,
     ProcessAlreadyFinished,
+    ProcessExited { code: Option<i32>, signal: Option<i32>, last_log_lines: Vec<String> },
     ProcessExitedWithErrorCode { code: Option<i32>, signal: Option<i32> },
     CannotConvertConfigToTOML,
+    AutogenerationNotStarted,
+    ZeroAutogenerationInterval,
+    ChainIsNotProgressing { round: u64 },
+    CannotStartOutportServer,
+    OutportStreamNotEnabled,
+    OptionNotSupportedByContainerBackend { option: String },
+    EventNotFound { max_blocks: u64 },
+    CannotStartEventNotifierReceiver,
+    EventNotifierNotEnabled,
+    CannotStartFakeElasticsearch,
+    ObserversNotFound,
+    ObserversDiscoveryNotSupported,
+    InvalidAddress { address: String },
 }
 
 impl Display for SimulatorError {
@@ -18,18 +30,15 @@ impl Display for SimulatorError {
             SimulatorError::CannotKillProcess => {
                 write!(f, "Cannot kill the simulator's process.")
             },
-            SimulatorError::TimedOutWhileWaitingToBeReady => {
-                write!(f, "Timed out while waiting to be ready.")
-            },
             SimulatorError::ProcessNotStarted => {
                 write!(f, "Simulator is not started. Please start it using the .start() method.")
             },
-            SimulatorError::StdoutAlreadyConsumed => {
-                write!(f, "Simulator's stdout has been already used.")
-            },
             SimulatorError::ProcessAlreadyFinished => {
                 write!(f, "Simulator already ended.")
             },
+            SimulatorError::ProcessExited { code, signal, last_log_lines } => {
+                write!(f, "Simulator exited with code {code:?} and signal {signal:?}. Last log lines:\n{}", last_log_lines.join("\n"))
+            },
             SimulatorError::ProcessExitedWithErrorCode { code, signal } => {
                 if let Some(code) = code {
                     write!(f, "Simulator exited with error code {code:#?} and signal: {signal:#?}.")
@@ -40,6 +49,45 @@ impl Display for SimulatorError {
             SimulatorError::CannotConvertConfigToTOML => {
                 write!(f, "Cannot convert config to TOML.")
             },
+            SimulatorError::AutogenerationNotStarted => {
+                write!(f, "Block autogeneration is not started. Please start it using the .start_autogeneration() method.")
+            },
+            SimulatorError::ZeroAutogenerationInterval => {
+                write!(f, "The block autogeneration interval can't be zero.")
+            },
+            SimulatorError::ChainIsNotProgressing { round } => {
+                write!(f, "Generating blocks did not advance the chain past round {round}.")
+            },
+            SimulatorError::CannotStartOutportServer => {
+                write!(f, "Cannot start the WebSocket server receiving the outport data.")
+            },
+            SimulatorError::OutportStreamNotEnabled => {
+                write!(f, "The outport stream is not enabled. Please start the simulator with the .with_outport_stream() option.")
+            },
+            SimulatorError::OptionNotSupportedByContainerBackend { option } => {
+                write!(f, "The {option} option is not supported by the container backend, the nodes can't reach the servers listening on the host's loopback.")
+            },
+            SimulatorError::EventNotFound { max_blocks } => {
+                write!(f, "No matching event was emitted after generating {max_blocks} blocks.")
+            },
+            SimulatorError::CannotStartEventNotifierReceiver => {
+                write!(f, "Cannot start the HTTP server receiving the events notifier payloads.")
+            },
+            SimulatorError::EventNotifierNotEnabled => {
+                write!(f, "The events notifier is not enabled. Please start the simulator with the .with_event_notifier() option.")
+            },
+            SimulatorError::CannotStartFakeElasticsearch => {
+                write!(f, "Cannot start the fake Elasticsearch server.")
+            },
+            SimulatorError::ObserversNotFound => {
+                write!(f, "Cannot find the observers' REST APIs among the ports the simulator's process listens on.")
+            },
+            SimulatorError::ObserversDiscoveryNotSupported => {
+                write!(f, "The observers can only be discovered when the simulator runs as a native process on Linux.")
+            },
+            SimulatorError::InvalidAddress { address } => {
+                write!(f, "{address} is not a valid bech32 address.")
+            },
         }
     }
 }
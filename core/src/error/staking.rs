@@ -0,0 +1,50 @@
+use std::fmt::{Display, Formatter};
+use crate::error::lib::LibError;
+
+#[derive(PartialEq, Debug, Clone)]
+pub enum StakingError {
+    NoValidatorKeys,
+    TransactionsSignatureNotBypassed,
+    CannotReadStakingConfig { file_path: String, key: String },
+    CannotReadValidatorKeys { file_path: String },
+    TransactionFailed { tx_hash: String, function: String, message: Option<String> },
+    TransactionNotExecuted { tx_hash: String, max_blocks: u64 },
+    DelegationContractNotFound { tx_hash: String },
+}
+
+impl Display for StakingError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            StakingError::NoValidatorKeys => {
+                write!(f, "At least one validator BLS key is needed.")
+            },
+            StakingError::TransactionsSignatureNotBypassed => {
+                write!(f, "The staking transactions are not signed, the simulator must be started with bypass_transactions_signature.")
+            },
+            StakingError::CannotReadStakingConfig { file_path, key } => {
+                write!(f, "Cannot read {key} from the config file {file_path}")
+            },
+            StakingError::CannotReadValidatorKeys { file_path } => {
+                write!(f, "Cannot read the validator keys of {file_path}")
+            },
+            StakingError::TransactionFailed { tx_hash, function, message } => {
+                match message {
+                    Some(message) => write!(f, "The {function} transaction {tx_hash} failed: {message}"),
+                    None => write!(f, "The {function} transaction {tx_hash} failed."),
+                }
+            },
+            StakingError::TransactionNotExecuted { tx_hash, max_blocks } => {
+                write!(f, "The transaction {tx_hash} was not executed after {max_blocks} blocks.")
+            },
+            StakingError::DelegationContractNotFound { tx_hash } => {
+                write!(f, "The transaction {tx_hash} didn't deploy a delegation contract.")
+            },
+        }
+    }
+}
+
+impl From<StakingError> for LibError {
+    fn from(value: StakingError) -> Self {
+        LibError::Staking(value)
+    }
+}
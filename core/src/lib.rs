@@ -2,9 +2,37 @@ mod utils;
 mod error;
 mod simulator;
 
+pub use simulator::api_routes::ApiRoutesConfig;
+pub use simulator::autogeneration::{AutogenerationState, AutogenerationStatus};
+pub use simulator::backend::SimulatorBackendKind;
+pub use simulator::batch::BatchReport;
+pub use simulator::elasticsearch::model::ElasticsearchDocument;
+pub use simulator::elasticsearch::server::FakeElasticsearch;
+pub use simulator::events::{ContractEvent, ContractEventStream, EventFilter};
+pub use simulator::backend::container::ContainerOptions;
 pub use simulator::model::Simulator;
+pub use simulator::notifier::model::{NotifierEvents, NotifierFinalizedBlock, NotifierRevertedBlock};
 pub use simulator::options::SimulatorOptions;
-pub use simulator::requests::set_state::SetStateAddress;
+pub use simulator::proxy_config::{ProxyApiVersion, ProxyOptions};
+pub use simulator::outport::model::{OutportBlock, OutportBlockData, OutportEvent, OutportEventLog, OutportFinalizedBlock, OutportLog, OutportLogData, OutportTransaction, OutportTransactionInfo, OutportTransactionPool};
+pub use simulator::outport::server::OutportStream;
+pub use simulator::readiness::ReadinessPolicy;
+pub use simulator::requests::about::About;
+pub use simulator::requests::block::{Block, Hyperblock, HyperblockShardBlock, MiniBlock, NotarizedBlock, ProducedBlock};
 pub use simulator::requests::initial_wallets::InitialWallets;
+pub use simulator::requests::network_config::NetworkConfig;
+pub use simulator::requests::network_economics::NetworkEconomics;
+pub use simulator::requests::network_status::NetworkStatus;
+pub use simulator::requests::send_transaction::Transaction;
+pub use simulator::requests::set_state::SetStateAddress;
+pub use simulator::requests::simulate_transaction::{Receipt, SimulationResults, TransactionSimulation};
+pub use simulator::requests::transaction::{EsdtTransfer, Event, SmartContractResult, TransactionLogs, TransactionOnNetwork};
+pub use simulator::requests::transaction_cost::TransactionCost;
+pub use simulator::requests::validator_statistics::ValidatorStatistics;
+pub use simulator::shard::ShardId;
+pub use simulator::staking::{ClaimedRewards, DELEGATION_MANAGER_SC_ADDRESS, DelegationContract, StakeResult, StakingTransaction, VALIDATOR_SC_ADDRESS};
+pub use simulator::supervisor::{SimulatorEvent, SimulatorExit};
+pub use utils::bls::BlsKey;
+pub use utils::binary::{SimulatorBinary, SimulatorBinarySource};
 
 include!(concat!(env!("OUT_DIR"), "/generated_code.rs"));
\ No newline at end of file
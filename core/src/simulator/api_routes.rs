@@ -0,0 +1,152 @@
+use toml::{Table, Value};
+
+use crate::error::lib::LibError;
+use crate::error::node_config::NodeConfigError;
+
+#[derive(Clone, PartialEq, Debug)]
+enum ApiRoutesOverride {
+    Package { package: String, open: bool },
+    Route { package: String, route: String, open: bool },
+}
+
+// The changes are applied in the order they are added.
+#[derive(Clone, PartialEq, Debug, Default)]
+pub struct ApiRoutesConfig {
+    overrides: Vec<ApiRoutesOverride>,
+}
+
+impl ApiRoutesConfig {
+    pub fn new() -> ApiRoutesConfig {
+        Self::default()
+    }
+
+    pub fn open_package(mut self, package: impl Into<String>) -> Self {
+        self.overrides.push(ApiRoutesOverride::Package { package: package.into(), open: true });
+
+        self
+    }
+
+    pub fn close_package(mut self, package: impl Into<String>) -> Self {
+        self.overrides.push(ApiRoutesOverride::Package { package: package.into(), open: false });
+
+        self
+    }
+
+    pub fn open_route(mut self, package: impl Into<String>, route: impl Into<String>) -> Self {
+        self.overrides.push(ApiRoutesOverride::Route { package: package.into(), route: route.into(), open: true });
+
+        self
+    }
+
+    pub fn close_route(mut self, package: impl Into<String>, route: impl Into<String>) -> Self {
+        self.overrides.push(ApiRoutesOverride::Route { package: package.into(), route: route.into(), open: false });
+
+        self
+    }
+
+    pub(crate) fn is_empty(&self) -> bool {
+        self.overrides.is_empty()
+    }
+
+    pub(crate) fn apply(&self, config: &mut Table, file_path: &str) -> Result<(), LibError> {
+        for api_routes_override in &self.overrides {
+            match api_routes_override {
+                ApiRoutesOverride::Package { package, open } => {
+                    for route in get_package_routes(config, file_path, package)? {
+                        route.insert("Open".to_string(), Value::Boolean(*open));
+                    }
+                },
+                ApiRoutesOverride::Route { package, route: route_name, open } => {
+                    let route = get_package_routes(config, file_path, package)?
+                        .find(|route| route.get("Name").and_then(|name| name.as_str()) == Some(route_name.as_str()));
+
+                    let Some(route) = route else {
+                        return Err(NodeConfigError::CannotFindRoute {
+                            file_path: file_path.to_string(),
+                            package: package.clone(),
+                            route: route_name.clone(),
+                        }.into());
+                    };
+
+                    route.insert("Open".to_string(), Value::Boolean(*open));
+                },
+            }
+        }
+
+        Ok(())
+    }
+}
+
+fn get_package_routes<'a>(config: &'a mut Table, file_path: &str, package: &str) -> Result<impl Iterator<Item = &'a mut Table>, LibError> {
+    let routes = config
+        .get_mut("APIPackages")
+        .and_then(|packages| packages.get_mut(package))
+        .and_then(|package| package.get_mut("Routes"))
+        .and_then(|routes| routes.as_array_mut());
+
+    let Some(routes) = routes else {
+        return Err(NodeConfigError::CannotFindSection { file_path: file_path.to_string(), section: format!("APIPackages.{package}") }.into());
+    };
+
+    Ok(routes.iter_mut().filter_map(|route| route.as_table_mut()))
+}
+
+#[cfg(test)]
+mod tests {
+    use toml::Table;
+
+    use crate::error::lib::LibError;
+    use crate::error::node_config::NodeConfigError;
+    use crate::simulator::api_routes::ApiRoutesConfig;
+
+    const API_CONFIG: &str = r#"
+[APIPackages.node]
+    Routes = [
+        { Name = "/status", Open = true },
+        { Name = "/metrics", Open = true },
+    ]
+
+[APIPackages.address]
+    Routes = [
+        { Name = "/:address", Open = true },
+    ]
+"#;
+
+    fn is_open(config: &Table, package: &str, route: &str) -> bool {
+        config["APIPackages"][package]["Routes"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .find(|entry| entry["Name"].as_str() == Some(route))
+            .unwrap()["Open"]
+            .as_bool()
+            .unwrap()
+    }
+
+    #[test]
+    fn test_overrides_are_applied_in_order() {
+        let mut config = API_CONFIG.parse::<Table>().unwrap();
+
+        ApiRoutesConfig::new()
+            .close_package("node")
+            .open_route("node", "/status")
+            .close_route("address", "/:address")
+            .apply(&mut config, "api.toml")
+            .unwrap();
+
+        assert!(is_open(&config, "node", "/status"));
+        assert!(!is_open(&config, "node", "/metrics"));
+        assert!(!is_open(&config, "address", "/:address"));
+    }
+
+    #[test]
+    fn test_unknown_package_is_an_error() {
+        let mut config = API_CONFIG.parse::<Table>().unwrap();
+
+        let result = ApiRoutesConfig::new()
+            .close_package("unknown")
+            .apply(&mut config, "api.toml");
+
+        assert!(matches!(result, Err(LibError::NodeConfig(NodeConfigError::CannotFindSection { .. }))));
+    }
+}
@@ -0,0 +1,228 @@
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+use tokio::sync::{broadcast, watch};
+use tokio::task::JoinHandle;
+use tokio::time::MissedTickBehavior;
+
+use crate::error::lib::LibError;
+use crate::error::simulator::SimulatorError;
+use crate::simulator::supervisor::SimulatorEvent;
+
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub enum AutogenerationState {
+    Stopped,
+    Running,
+    Paused,
+}
+
+#[derive(Clone, PartialEq, Debug)]
+pub struct AutogenerationStatus {
+    pub state: AutogenerationState,
+    pub interval: Option<Duration>,
+    pub blocks_per_tick: u64,
+    pub generated_blocks: u64,
+    pub last_error: Option<LibError>,
+}
+
+impl Default for AutogenerationStatus {
+    fn default() -> Self {
+        Self {
+            state: AutogenerationState::Stopped,
+            interval: None,
+            blocks_per_tick: 0,
+            generated_blocks: 0,
+            last_error: None,
+        }
+    }
+}
+
+pub(crate) struct AutogenerationTask {
+    task: JoinHandle<()>,
+    is_paused: watch::Sender<bool>,
+}
+
+// The block generation loop keeps going across restarts and reports its errors instead of ending on them.
+#[derive(Clone, Default)]
+pub(crate) struct Autogeneration {
+    task: Arc<Mutex<Option<AutogenerationTask>>>,
+    status: Arc<Mutex<AutogenerationStatus>>,
+}
+
+impl Autogeneration {
+    // A tick is skipped when `generate_blocks` returns `None`, as no simulator is running.
+    pub(crate) fn start<F, Fut>(&self, interval: Duration, blocks_per_tick: u64, events: broadcast::Sender<SimulatorEvent>, generate_blocks: F) -> Result<(), LibError>
+    where
+        F: Fn(u64) -> Fut + Send + 'static,
+        Fut: std::future::Future<Output = Option<Result<(), LibError>>> + Send,
+    {
+        check_interval(interval)?;
+
+        self.stop();
+
+        *self.status.lock().unwrap() = AutogenerationStatus {
+            state: AutogenerationState::Running,
+            interval: Some(interval),
+            blocks_per_tick,
+            generated_blocks: 0,
+            last_error: None,
+        };
+
+        let (is_paused, mut is_paused_receiver) = watch::channel(false);
+        let status = self.status.clone();
+
+        let task = tokio::spawn(async move {
+            let mut ticker = tokio::time::interval(interval);
+            ticker.set_missed_tick_behavior(MissedTickBehavior::Delay);
+
+            loop {
+                ticker.tick().await;
+
+                if is_paused_receiver.wait_for(|is_paused| !is_paused).await.is_err() {
+                    break
+                }
+
+                let Some(result) = generate_blocks(blocks_per_tick).await else {
+                    continue
+                };
+
+                let mut status = status.lock().unwrap();
+                match result {
+                    Ok(()) => status.generated_blocks += blocks_per_tick,
+                    Err(error) => {
+                        status.last_error = Some(error.clone());
+                        let _ = events.send(SimulatorEvent::AutogenerationFailed(error)); // There might be no subscriber.
+                    }
+                }
+            }
+        });
+
+        *self.task.lock().unwrap() = Some(AutogenerationTask { task, is_paused });
+
+        Ok(())
+    }
+
+    pub(crate) fn set_paused(&self, paused: bool) -> bool {
+        let task = self.task.lock().unwrap();
+
+        let Some(task) = task.as_ref() else {
+            return false;
+        };
+
+        task.is_paused.send_replace(paused);
+        self.status.lock().unwrap().state = if paused { AutogenerationState::Paused } else { AutogenerationState::Running };
+
+        true
+    }
+
+    pub(crate) fn stop(&self) {
+        if let Some(task) = self.task.lock().unwrap().take() {
+            task.task.abort();
+        }
+
+        self.status.lock().unwrap().state = AutogenerationState::Stopped;
+    }
+
+    pub(crate) fn get_status(&self) -> AutogenerationStatus {
+        self.status.lock().unwrap().clone()
+    }
+}
+
+// `tokio::time::interval` panics on a zero period, it has to be rejected before the loop is spawned.
+pub(crate) fn check_interval(interval: Duration) -> Result<(), LibError> {
+    if interval.is_zero() {
+        return Err(SimulatorError::ZeroAutogenerationInterval.into());
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+    use std::sync::atomic::{AtomicU64, Ordering};
+    use std::time::Duration;
+
+    use tokio::sync::broadcast;
+
+    use crate::error::lib::LibError;
+    use crate::error::simulator::SimulatorError;
+    use crate::simulator::autogeneration::{Autogeneration, AutogenerationState};
+
+    // Moves the paused clock one tick at a time, the extra millisecond covers the timer's rounding of the deadlines.
+    // The loop is first left to start, so its interval begins before the clock moves.
+    async fn advance_ticks(interval: Duration, num_of_ticks: u32) {
+        run_pending_tasks().await;
+
+        for _ in 0..num_of_ticks {
+            tokio::time::advance(interval + Duration::from_millis(1)).await;
+            run_pending_tasks().await;
+        }
+    }
+
+    async fn run_pending_tasks() {
+        for _ in 0..100 {
+            tokio::task::yield_now().await;
+        }
+    }
+
+    #[tokio::test]
+    async fn test_start_twice_keeps_a_single_loop() {
+        tokio::time::pause();
+
+        let autogeneration = Autogeneration::default();
+        let calls = Arc::new(AtomicU64::new(0));
+        let interval = Duration::from_millis(10);
+
+        for _ in 0..2 {
+            let calls = calls.clone();
+            autogeneration.start(interval, 3, broadcast::channel(16).0, move |_| {
+                let calls = calls.clone();
+                async move {
+                    calls.fetch_add(1, Ordering::SeqCst);
+                    Some(Ok(()))
+                }
+            }).unwrap();
+        }
+
+        advance_ticks(interval, 10).await;
+        autogeneration.stop();
+
+        let status = autogeneration.get_status();
+
+        assert_eq!(status.state, AutogenerationState::Stopped);
+        assert_eq!(calls.load(Ordering::SeqCst), 10);
+        assert_eq!(status.generated_blocks, 30);
+    }
+
+    #[tokio::test]
+    async fn test_pause_stops_generating() {
+        tokio::time::pause();
+
+        let autogeneration = Autogeneration::default();
+        let interval = Duration::from_millis(5);
+
+        autogeneration.start(interval, 1, broadcast::channel(16).0, |_| async { Some(Ok(())) }).unwrap();
+        advance_ticks(interval, 5).await;
+
+        assert!(autogeneration.set_paused(true));
+        let generated_blocks = autogeneration.get_status().generated_blocks;
+        advance_ticks(interval, 5).await;
+
+        let status = autogeneration.get_status();
+
+        assert_eq!(generated_blocks, 5);
+        assert_eq!(status.state, AutogenerationState::Paused);
+        assert_eq!(status.generated_blocks, generated_blocks);
+    }
+
+    #[tokio::test]
+    async fn test_zero_interval_is_rejected() {
+        let autogeneration = Autogeneration::default();
+
+        let result = autogeneration.start(Duration::ZERO, 1, broadcast::channel(16).0, |_| async { Some(Ok(())) });
+
+        assert_eq!(result, Err(LibError::Simulator(SimulatorError::ZeroAutogenerationInterval)));
+        assert_eq!(autogeneration.get_status().state, AutogenerationState::Stopped);
+    }
+}
@@ -0,0 +1,187 @@
+use std::path::{Path, PathBuf};
+use std::process::{Child, Command, Stdio};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use nix::sys::signal::{kill, Signal};
+use nix::unistd::Pid;
+
+use crate::error::lib::LibError;
+use crate::error::process::ProcessError;
+use crate::simulator::backend::SimulatorBackend;
+use crate::SimulatorOptions;
+use crate::utils::binary::{CONFIG_FOLDER, SimulatorBinary};
+use crate::utils::process::{copy_assets_to_temp_dir, write_simulator_config};
+
+#[derive(Clone, PartialEq, Debug)]
+pub struct ContainerOptions {
+    pub(crate) runtime: String,
+    pub(crate) image: String,
+    pub(crate) working_dir: String,
+}
+
+impl ContainerOptions {
+    pub fn new() -> ContainerOptions {
+        Self::default()
+    }
+
+    // `docker` and `podman` are supported.
+    pub fn with_runtime(mut self, runtime: impl Into<String>) -> Self {
+        self.runtime = runtime.into();
+
+        self
+    }
+
+    pub fn with_image(mut self, image: impl Into<String>) -> Self {
+        self.image = image.into();
+
+        self
+    }
+
+    // The generated config is mounted in this directory of the image.
+    pub fn with_working_dir(mut self, working_dir: impl Into<String>) -> Self {
+        self.working_dir = working_dir.into();
+
+        self
+    }
+}
+
+impl Default for ContainerOptions {
+    fn default() -> Self {
+        Self {
+            runtime: "docker".to_string(),
+            image: "multiversx/chainsimulator:latest".to_string(),
+            working_dir: "/multiversx".to_string(),
+        }
+    }
+}
+
+pub(crate) struct ContainerBackend {
+    options: ContainerOptions,
+    container_name: String,
+}
+
+impl ContainerBackend {
+    pub(crate) fn new(options: ContainerOptions) -> ContainerBackend {
+        let nanos = SystemTime::now().duration_since(UNIX_EPOCH).unwrap().as_nanos();
+        let container_name = format!("mx-chain-simulator-{}-{nanos}", std::process::id());
+
+        ContainerBackend {
+            options,
+            container_name,
+        }
+    }
+
+    fn get_run_args(&self, working_dir: &Path, options: &SimulatorOptions) -> Vec<String> {
+        let container_working_dir = PathBuf::from(&self.options.working_dir);
+        let port_mapping = format!("{}:{}", options.server_port, options.server_port);
+        let config_mount = format!(
+            "{}:{}",
+            working_dir.join(CONFIG_FOLDER).display(),
+            container_working_dir.join(CONFIG_FOLDER).display()
+        );
+
+        // An absolute logs path is mounted as-is so the files are written where the caller expects them.
+        let logs_mount = format!(
+            "{}:{}",
+            working_dir.join(&options.logs_path).display(),
+            container_working_dir.join(&options.logs_path).display()
+        );
+
+        let mut args = vec![
+            "run".to_string(),
+            "--rm".to_string(),
+            "--name".to_string(),
+            self.container_name.clone(),
+            "-p".to_string(),
+            port_mapping,
+            "-v".to_string(),
+            config_mount,
+            "-v".to_string(),
+            logs_mount,
+            self.options.image.clone(),
+        ];
+
+        args.extend(options.to_cli_args());
+
+        args
+    }
+}
+
+impl SimulatorBackend for ContainerBackend {
+    fn prepare(&self, working_dir: &Path, _options: &SimulatorOptions) -> Result<Option<SimulatorBinary>, LibError> {
+        copy_assets_to_temp_dir(working_dir)?;
+
+        Ok(None)
+    }
+
+    fn spawn(&self, working_dir: &Path, config_content: &[u8], options: &SimulatorOptions) -> Result<Child, LibError> {
+        write_simulator_config(working_dir, config_content)?;
+
+        Command::new(&self.options.runtime)
+            .current_dir(working_dir)
+            .args(self.get_run_args(working_dir, options))
+            .stdout(Stdio::piped())
+            .spawn()
+            .map_err(|_| ProcessError::CannotSpawnProcess.into())
+    }
+
+    fn kill(&self, process_id: u32) -> Result<(), LibError> {
+        let _ = kill(Pid::from_raw(process_id as i32), Signal::SIGKILL);
+
+        // Killing the CLI doesn't always stop the container, so we explicitly remove it.
+        let Ok(_) = Command::new(&self.options.runtime)
+            .args(["rm", "-f", &self.container_name])
+            .stdout(Stdio::null())
+            .stderr(Stdio::null())
+            .status()
+            else {
+                return Err(ProcessError::CannotStopContainer { name: self.container_name.clone() }.into());
+            };
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+
+    use crate::ContainerOptions;
+    use crate::simulator::backend::container::ContainerBackend;
+    use crate::simulator::backend::SimulatorBackend;
+    use crate::SimulatorOptions;
+    use crate::utils::fs::{get_temp_dir, write_bytes_to_temp_file};
+
+    #[test]
+    fn test_spawn_with_fake_runtime() {
+        let runtime_dir = get_temp_dir().unwrap();
+        let recorded_args_path = runtime_dir.path().join("args.txt");
+        let script = format!("#!/bin/sh\necho \"$@\" > {}\n", recorded_args_path.display());
+        let runtime_path = write_bytes_to_temp_file("fake-runtime", runtime_dir.path(), script.as_bytes()).unwrap();
+
+        #[cfg(unix)]
+        {
+            use std::os::unix::fs::PermissionsExt;
+            fs::set_permissions(&runtime_path, fs::Permissions::from_mode(0o755)).unwrap();
+        }
+
+        let container_options = ContainerOptions::new()
+            .with_runtime(runtime_path.to_str().unwrap())
+            .with_image("chainsimulator:test");
+        let backend = ContainerBackend::new(container_options);
+
+        let working_dir = get_temp_dir().unwrap();
+        fs::create_dir_all(working_dir.path().join("config")).unwrap();
+
+        let options = SimulatorOptions::new().with_server_port(9091);
+        let mut child = backend.spawn(working_dir.path(), b"", &options).unwrap();
+        assert!(child.wait().unwrap().success());
+
+        let recorded_args = fs::read_to_string(recorded_args_path).unwrap();
+        let expected_config_mount = format!("-v {}/config:/multiversx/config", working_dir.path().display());
+
+        assert!(recorded_args.starts_with(&format!("run --rm --name {} -p 9091:9091", backend.container_name)));
+        assert!(recorded_args.contains(&expected_config_mount));
+        assert!(recorded_args.contains("chainsimulator:test --server-port 9091"));
+    }
+}
@@ -0,0 +1,37 @@
+pub(crate) mod container;
+pub(crate) mod native;
+
+use std::path::Path;
+use std::process::Child;
+use std::sync::Arc;
+
+use crate::error::lib::LibError;
+use crate::simulator::backend::container::{ContainerBackend, ContainerOptions};
+use crate::simulator::backend::native::NativeBackend;
+use crate::SimulatorOptions;
+use crate::utils::binary::SimulatorBinary;
+
+#[derive(Clone, PartialEq, Debug, Default)]
+pub enum SimulatorBackendKind {
+    #[default]
+    NativeProcess,
+    Container(ContainerOptions),
+}
+
+// Only the way the process is started and stopped depends on the backend.
+pub(crate) trait SimulatorBackend: Send + Sync {
+    fn prepare(&self, working_dir: &Path, options: &SimulatorOptions) -> Result<Option<SimulatorBinary>, LibError>;
+
+    fn spawn(&self, working_dir: &Path, config_content: &[u8], options: &SimulatorOptions) -> Result<Child, LibError>;
+
+    fn kill(&self, process_id: u32) -> Result<(), LibError>;
+}
+
+impl SimulatorBackendKind {
+    pub(crate) fn create_backend(&self) -> Arc<dyn SimulatorBackend> {
+        match self {
+            SimulatorBackendKind::NativeProcess => Arc::new(NativeBackend),
+            SimulatorBackendKind::Container(options) => Arc::new(ContainerBackend::new(options.clone())),
+        }
+    }
+}
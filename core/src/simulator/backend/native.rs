@@ -0,0 +1,31 @@
+use std::path::Path;
+use std::process::Child;
+
+use nix::sys::signal::{kill, Signal};
+use nix::unistd::Pid;
+
+use crate::error::lib::LibError;
+use crate::simulator::backend::SimulatorBackend;
+use crate::SimulatorOptions;
+use crate::utils::binary::SimulatorBinary;
+use crate::utils::process::{prepare_temp_dir_for_simulator, spawn_simulator_process};
+
+pub(crate) struct NativeBackend;
+
+impl SimulatorBackend for NativeBackend {
+    fn prepare(&self, working_dir: &Path, options: &SimulatorOptions) -> Result<Option<SimulatorBinary>, LibError> {
+        let binary = prepare_temp_dir_for_simulator(working_dir, options.binary_path.as_deref())?;
+
+        Ok(Some(binary))
+    }
+
+    fn spawn(&self, working_dir: &Path, config_content: &[u8], options: &SimulatorOptions) -> Result<Child, LibError> {
+        spawn_simulator_process(working_dir, config_content, options.to_cli_args())
+    }
+
+    fn kill(&self, process_id: u32) -> Result<(), LibError> {
+        let _ = kill(Pid::from_raw(process_id as i32), Signal::SIGKILL);
+
+        Ok(())
+    }
+}
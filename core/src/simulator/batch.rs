@@ -0,0 +1,207 @@
+use std::collections::HashMap;
+
+use reqwest::Client;
+
+use crate::error::lib::LibError;
+use crate::error::requests::send_multiple_transactions::SendMultipleTransactionsError;
+use crate::simulator::model::{generate_blocks, Simulator};
+use crate::simulator::node_config::read_tx_pool_size_per_sender;
+use crate::simulator::requests::send_multiple_transactions::SendMultipleTransactionsResponse;
+use crate::simulator::requests::send_transaction::{Transaction, TransactionRequest};
+
+const MAX_BLOCKS_TO_PROCESS_PENDING_TRANSACTIONS: u64 = 100;
+pub(crate) const PENDING_TRANSACTION_STATUSES: [&str; 3] = ["received", "pending", "partially-executed"];
+
+#[derive(Clone, PartialEq, Debug, Default)]
+pub struct BatchReport {
+    pub sent: u64,
+    pub rejected: u64,
+    pub executed: u64,
+    pub failed: u64,
+    pub pending: u64,
+    pub total_gas_used: u64,
+    pub blocks_generated: u64,
+    pub transactions_hashes: Vec<String>,
+}
+
+impl Simulator {
+    // A batch never holds more transactions from a single sender than the node's mempool accepts.
+    pub async fn send_transactions_batched<I>(&self, transactions: I, batch_size: usize) -> Result<BatchReport, LibError>
+    where
+        I: IntoIterator<Item = Transaction>,
+    {
+        let (_, options) = self.get_process_id_and_options().await?;
+
+        let size_per_sender = read_tx_pool_size_per_sender(self.get_tempdir_path());
+        let batches = split_into_batches(transactions, batch_size, size_per_sender);
+
+        let mut report = BatchReport::default();
+
+        for batch in batches {
+            // On errors, the nonces already reserved for the batch are not used, the senders are resynced on their next transaction.
+            let mut requests = Vec::with_capacity(batch.len());
+            for transaction in &batch {
+                match self.prepare_transaction_with_managed_nonce(options.server_port, transaction).await {
+                    Ok(request) => requests.push(request),
+                    Err(error) => {
+                        self.invalidate_senders(&batch).await;
+                        return Err(error);
+                    }
+                }
+            }
+
+            let hashes = match send_multiple_transactions(options.server_port, &requests).await {
+                Ok(hashes) => hashes,
+                Err(error) => {
+                    self.invalidate_senders(&batch).await;
+                    return Err(error);
+                }
+            };
+
+            for (index, transaction) in batch.iter().enumerate() {
+                match hashes.get(&index) {
+                    Some(hash) => report.transactions_hashes.push(hash.clone()),
+                    None => {
+                        report.rejected += 1;
+                        self.get_nonce_manager().invalidate(&transaction.sender).await;
+                    }
+                }
+            }
+
+            generate_blocks(options.server_port, 1).await?;
+            report.blocks_generated += 1;
+        }
+
+        report.sent = report.transactions_hashes.len() as u64;
+
+        let mut pending_hashes = report.transactions_hashes.clone();
+        let mut waiting_blocks = 0;
+        loop {
+            let mut still_pending_hashes = vec![];
+
+            for hash in pending_hashes {
+                let transaction = self.get_transaction(&hash).await?;
+
+                if PENDING_TRANSACTION_STATUSES.contains(&transaction.status.as_str()) {
+                    still_pending_hashes.push(hash);
+                    continue;
+                }
+
+                report.total_gas_used += transaction.gas_used;
+
+                if transaction.is_success() {
+                    report.executed += 1;
+                } else {
+                    report.failed += 1;
+                }
+            }
+
+            if still_pending_hashes.is_empty() || waiting_blocks >= MAX_BLOCKS_TO_PROCESS_PENDING_TRANSACTIONS {
+                report.pending = still_pending_hashes.len() as u64;
+                break;
+            }
+
+            generate_blocks(options.server_port, 1).await?;
+            report.blocks_generated += 1;
+            waiting_blocks += 1;
+            pending_hashes = still_pending_hashes;
+        }
+
+        Ok(report)
+    }
+
+    async fn invalidate_senders(&self, transactions: &[Transaction]) {
+        for transaction in transactions {
+            self.get_nonce_manager().invalidate(&transaction.sender).await;
+        }
+    }
+}
+
+async fn send_multiple_transactions(server_port: u16, requests: &[TransactionRequest]) -> Result<HashMap<usize, String>, LibError> {
+    let url = format!("http://localhost:{}/transaction/send-multiple", server_port);
+
+    let Ok(body) = serde_json::to_string(requests) else {
+        return Err(SendMultipleTransactionsError::CannotConvertTransactionsAsJSON { url }.into())
+    };
+
+    let Ok(response) = Client::new()
+        .post(&url)
+        .body(body)
+        .send()
+        .await
+        else {
+            return Err(SendMultipleTransactionsError::CannotSendRequest { url }.into());
+        };
+
+    if !response.status().is_success() {
+        return Err(SendMultipleTransactionsError::ResponseStatusIsNotSuccessful { url, status: response.status().as_u16() }.into());
+    }
+
+    let Ok(text) = response.text().await else {
+        return Err(SendMultipleTransactionsError::CannotGetTextFromTheResponse { url }.into());
+    };
+
+    let Ok(result) = serde_json::from_str::<SendMultipleTransactionsResponse>(&text) else {
+        return Err(SendMultipleTransactionsError::FailedToParseTheResponse { url, response: text }.into());
+    };
+
+    if result.code != "successful" {
+        return Err(SendMultipleTransactionsError::ResponseCodeIsNotSuccessful { url, code: result.code }.into());
+    }
+
+    let Some(data) = result.data else {
+        return Err(SendMultipleTransactionsError::ResponseCodeIsNotSuccessful { url, code: result.code }.into());
+    };
+
+    Ok(data.txs_hashes)
+}
+
+fn split_into_batches<I>(transactions: I, batch_size: usize, size_per_sender: Option<usize>) -> Vec<Vec<Transaction>>
+where
+    I: IntoIterator<Item = Transaction>,
+{
+    let batch_size = batch_size.max(1);
+    let size_per_sender = size_per_sender.unwrap_or(usize::MAX).max(1);
+
+    let mut batches = vec![];
+    let mut batch: Vec<Transaction> = vec![];
+    let mut senders_count: HashMap<String, usize> = HashMap::new();
+
+    for transaction in transactions {
+        let sender_count = senders_count.get(&transaction.sender).copied().unwrap_or_default();
+
+        if batch.len() == batch_size || sender_count == size_per_sender {
+            batches.push(std::mem::take(&mut batch));
+            senders_count.clear();
+        }
+
+        *senders_count.entry(transaction.sender.clone()).or_default() += 1;
+        batch.push(transaction);
+    }
+
+    if !batch.is_empty() {
+        batches.push(batch);
+    }
+
+    batches
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::simulator::batch::split_into_batches;
+    use crate::Transaction;
+
+    #[test]
+    fn test_split_into_batches_respects_size_per_sender() {
+        let transactions = ["alice", "alice", "bob", "alice", "bob"]
+            .into_iter()
+            .map(|sender| Transaction::new(sender, "erd1receiver"));
+
+        let batches: Vec<Vec<String>> = split_into_batches(transactions, 4, Some(2))
+            .into_iter()
+            .map(|batch| batch.into_iter().map(|transaction| transaction.sender).collect())
+            .collect();
+
+        assert_eq!(batches, vec![vec!["alice", "alice", "bob"], vec!["alice", "bob"]]);
+    }
+}
@@ -33,13 +33,13 @@ pub(crate) struct SimulatorConfigSimulator {
 #[derive(Serialize, Deserialize, Debug)]
 pub(crate) struct SimulatorConfigLogs {
     #[serde(rename = "log-file-life-span-in-mb")]
-    log_file_life_span_in_mb: u64,
+    pub(crate) log_file_life_span_in_mb: u64,
     #[serde(rename = "log-file-life-span-in-sec")]
-    log_file_life_span_in_sec: u64,
+    pub(crate) log_file_life_span_in_sec: u64,
     #[serde(rename = "log-file-prefix")]
-    log_file_prefix: String,
+    pub(crate) log_file_prefix: String,
     #[serde(rename = "logs-path")]
-    logs_path: String,
+    pub(crate) logs_path: String,
 }
 
 impl SimulatorConfig {
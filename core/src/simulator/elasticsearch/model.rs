@@ -0,0 +1,163 @@
+use std::collections::HashMap;
+
+use serde_json::{json, Map, Value};
+
+#[derive(Clone, PartialEq, Debug)]
+pub struct ElasticsearchDocument {
+    pub id: Option<String>,
+    pub source: Value,
+}
+
+pub(crate) type ElasticsearchIndexes = HashMap<String, Vec<ElasticsearchDocument>>;
+
+// Scripted updates are not evaluated: an existing document is left unchanged and a missing one is created from the upsert.
+pub(crate) fn apply_bulk_request(indexes: &mut ElasticsearchIndexes, default_index: Option<&str>, body: &[u8]) -> Vec<Value> {
+    let mut lines = body
+        .split(|byte| *byte == b'\n')
+        .filter(|line| !line.iter().all(u8::is_ascii_whitespace));
+
+    let mut items = vec![];
+
+    while let Some(action_line) = lines.next() {
+        let Ok(Value::Object(action)) = serde_json::from_slice::<Value>(action_line) else {
+            continue;
+        };
+
+        let Some((operation, metadata)) = action.into_iter().next() else {
+            continue;
+        };
+
+        let index = metadata.get("_index")
+            .and_then(Value::as_str)
+            .or(default_index)
+            .unwrap_or_default()
+            .to_string();
+        let id = metadata.get("_id").and_then(Value::as_str).map(str::to_string);
+
+        if operation != "delete" {
+            let Some(source_line) = lines.next() else {
+                break;
+            };
+
+            let source = serde_json::from_slice::<Value>(source_line).unwrap_or_default();
+            let documents = indexes.entry(index.clone()).or_default();
+
+            match operation.as_str() {
+                "update" => update_document(documents, id.clone(), source),
+                _ => upsert_document(documents, id.clone(), source),
+            }
+        } else if let Some(documents) = indexes.get_mut(&index) {
+            documents.retain(|document| document.id != id);
+        }
+
+        let mut item = Map::new();
+        item.insert(operation, json!({ "_index": index, "_id": id, "status": 200 }));
+        items.push(Value::Object(item));
+    }
+
+    items
+}
+
+pub(crate) fn get_multiple_documents(indexes: &ElasticsearchIndexes, default_index: Option<&str>, body: &[u8]) -> Vec<Value> {
+    let Ok(request) = serde_json::from_slice::<Value>(body) else {
+        return vec![];
+    };
+
+    let mut requested: Vec<(Option<String>, String)> = vec![];
+    if let Some(ids) = request.get("ids").and_then(Value::as_array) {
+        requested.extend(ids.iter().filter_map(Value::as_str).map(|id| (None, id.to_string())));
+    }
+    if let Some(docs) = request.get("docs").and_then(Value::as_array) {
+        for doc in docs {
+            let Some(id) = doc.get("_id").and_then(Value::as_str) else {
+                continue;
+            };
+
+            let index = doc.get("_index").and_then(Value::as_str).map(str::to_string);
+            requested.push((index, id.to_string()));
+        }
+    }
+
+    requested
+        .into_iter()
+        .map(|(index, id)| {
+            let index = index.or(default_index.map(str::to_string)).unwrap_or_default();
+
+            let document = indexes.get(&index)
+                .and_then(|documents| documents.iter().find(|document| document.id.as_deref() == Some(id.as_str())));
+
+            match document {
+                Some(document) => json!({ "_index": index, "_id": id, "found": true, "_source": document.source }),
+                None => json!({ "_index": index, "_id": id, "found": false }),
+            }
+        })
+        .collect()
+}
+
+fn upsert_document(documents: &mut Vec<ElasticsearchDocument>, id: Option<String>, source: Value) {
+    let existing = id.as_ref()
+        .and_then(|id| documents.iter_mut().find(|document| document.id.as_ref() == Some(id)));
+
+    match existing {
+        Some(document) => document.source = source,
+        None => documents.push(ElasticsearchDocument { id, source }),
+    }
+}
+
+fn update_document(documents: &mut Vec<ElasticsearchDocument>, id: Option<String>, update: Value) {
+    let existing = id.as_ref()
+        .and_then(|id| documents.iter_mut().find(|document| document.id.as_ref() == Some(id)));
+
+    match existing {
+        Some(document) => {
+            if let (Value::Object(source), Some(Value::Object(doc))) = (&mut document.source, update.get("doc")) {
+                source.extend(doc.clone());
+            }
+        },
+        None => {
+            let source = update.get("upsert")
+                .or(update.get("doc"))
+                .cloned()
+                .unwrap_or(Value::Object(Map::new()));
+
+            documents.push(ElasticsearchDocument { id, source });
+        },
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use crate::simulator::elasticsearch::model::{apply_bulk_request, ElasticsearchIndexes, get_multiple_documents};
+
+    #[test]
+    fn test_bulk_request_operations() {
+        let mut indexes = ElasticsearchIndexes::default();
+
+        let body = concat!(
+            r#"{"index":{"_index":"blocks","_id":"aa"}}"#, "\n",
+            r#"{"nonce":1,"shardId":0}"#, "\n",
+            r#"{"index":{"_id":"bb"}}"#, "\n",
+            r#"{"nonce":2,"shardId":0}"#, "\n",
+            r#"{"update":{"_index":"accounts","_id":"erd1"}}"#, "\n",
+            r#"{"script":{"source":"ctx._source.balance = params.balance"},"upsert":{"balance":"10"}}"#, "\n",
+            r#"{"update":{"_index":"blocks","_id":"aa"}}"#, "\n",
+            r#"{"doc":{"proposer":3},"doc_as_upsert":true}"#, "\n",
+            r#"{"delete":{"_index":"blocks","_id":"bb"}}"#, "\n",
+        );
+
+        let items = apply_bulk_request(&mut indexes, Some("blocks"), body.as_bytes());
+
+        assert_eq!(items.len(), 5);
+        assert_eq!(indexes["blocks"].len(), 1);
+        assert_eq!(indexes["blocks"][0].source, json!({"nonce": 1, "shardId": 0, "proposer": 3}));
+        assert_eq!(indexes["accounts"][0].source, json!({"balance": "10"}));
+
+        let docs = get_multiple_documents(&indexes, Some("accounts"), br#"{"ids":["erd1","erd2"]}"#);
+
+        assert_eq!(docs[0]["found"], json!(true));
+        assert_eq!(docs[0]["_source"]["balance"], json!("10"));
+        assert_eq!(docs[1]["found"], json!(false));
+    }
+}
@@ -0,0 +1,176 @@
+use std::convert::Infallible;
+use std::net::SocketAddr;
+use std::sync::{Arc, Mutex};
+
+use hyper::{Body, Method, Request, Response, StatusCode};
+use hyper::header::CONTENT_TYPE;
+use serde_json::{json, Value};
+use tokio::task::JoinHandle;
+
+use crate::error::lib::LibError;
+use crate::error::simulator::SimulatorError;
+use crate::simulator::elasticsearch::model::{apply_bulk_request, ElasticsearchDocument, ElasticsearchIndexes, get_multiple_documents};
+use crate::utils::local_server::spawn_local_http_server;
+
+// Recent Elasticsearch clients refuse to talk to a server not returning this header.
+const PRODUCT_HEADER_NAME: &str = "X-Elastic-Product";
+const PRODUCT_HEADER_VALUE: &str = "Elasticsearch";
+const EMULATED_VERSION: &str = "7.16.2";
+
+// Indexes, templates, aliases and policies are all reported as existing, and searches never return hits.
+pub struct FakeElasticsearch {
+    address: SocketAddr,
+    indexes: Arc<Mutex<ElasticsearchIndexes>>,
+    task: JoinHandle<()>,
+}
+
+impl FakeElasticsearch {
+    pub fn start() -> Result<FakeElasticsearch, LibError> {
+        let indexes = Arc::new(Mutex::new(ElasticsearchIndexes::default()));
+
+        let service_indexes = indexes.clone();
+        let handle = move |request| handle_request(request, service_indexes.clone());
+
+        let Some((address, task)) = spawn_local_http_server(handle) else {
+            return Err(SimulatorError::CannotStartFakeElasticsearch.into());
+        };
+
+        Ok(FakeElasticsearch { address, indexes, task })
+    }
+
+    pub fn url(&self) -> String {
+        format!("http://{}", self.address)
+    }
+
+    pub fn get_indexes(&self) -> Vec<String> {
+        let mut indexes: Vec<String> = self.indexes.lock().unwrap().keys().cloned().collect();
+        indexes.sort();
+
+        indexes
+    }
+
+    pub fn get_documents(&self, index: &str) -> Vec<ElasticsearchDocument> {
+        self.indexes.lock().unwrap().get(index).cloned().unwrap_or_default()
+    }
+
+    pub fn get_document(&self, index: &str, id: &str) -> Option<ElasticsearchDocument> {
+        self.indexes.lock().unwrap()
+            .get(index)?
+            .iter()
+            .find(|document| document.id.as_deref() == Some(id))
+            .cloned()
+    }
+
+    pub fn clear(&self) {
+        self.indexes.lock().unwrap().clear();
+    }
+}
+
+impl Drop for FakeElasticsearch {
+    fn drop(&mut self) {
+        self.task.abort();
+    }
+}
+
+async fn handle_request(request: Request<Body>, indexes: Arc<Mutex<ElasticsearchIndexes>>) -> Result<Response<Body>, Infallible> {
+    let method = request.method().clone();
+    let segments: Vec<String> = request.uri()
+        .path()
+        .split('/')
+        .filter(|segment| !segment.is_empty())
+        .map(str::to_string)
+        .collect();
+
+    let Ok(body) = hyper::body::to_bytes(request.into_body()).await else {
+        return Ok(json_response(StatusCode::BAD_REQUEST, json!({})));
+    };
+
+    // The index is the first segment of paths such as `/blocks/_bulk`, while the API ones start with an underscore.
+    let default_index = segments.first()
+        .filter(|segment| !segment.starts_with('_'))
+        .map(String::as_str);
+
+    let response = match (method, segments.last().map(String::as_str)) {
+        (Method::HEAD, _) => json_response(StatusCode::OK, json!({})),
+        (Method::GET, None) => json_response(StatusCode::OK, json!({
+            "name": "fake-elasticsearch",
+            "cluster_name": "fake-elasticsearch",
+            "version": { "number": EMULATED_VERSION, "build_flavor": "default" },
+            "tagline": "You Know, for Search",
+        })),
+        (_, Some("_bulk")) => {
+            let items = apply_bulk_request(&mut indexes.lock().unwrap(), default_index, &body);
+
+            json_response(StatusCode::OK, json!({ "took": 0, "errors": false, "items": items }))
+        },
+        (_, Some("_mget")) => {
+            let docs = get_multiple_documents(&indexes.lock().unwrap(), default_index, &body);
+
+            json_response(StatusCode::OK, json!({ "docs": docs }))
+        },
+        (_, Some("_search")) => json_response(StatusCode::OK, json!({
+            "took": 0,
+            "timed_out": false,
+            "hits": { "total": { "value": 0, "relation": "eq" }, "hits": [] },
+        })),
+        (_, Some("_count")) => json_response(StatusCode::OK, json!({ "count": 0 })),
+        _ => json_response(StatusCode::OK, json!({ "acknowledged": true })),
+    };
+
+    Ok(response)
+}
+
+fn json_response(status: StatusCode, body: Value) -> Response<Body> {
+    let mut response = Response::new(Body::from(body.to_string()));
+    *response.status_mut() = status;
+
+    let headers = response.headers_mut();
+    headers.insert(CONTENT_TYPE, "application/json".parse().unwrap());
+    headers.insert(PRODUCT_HEADER_NAME, PRODUCT_HEADER_VALUE.parse().unwrap());
+
+    response
+}
+
+#[cfg(test)]
+mod tests {
+    use reqwest::Client;
+    use serde_json::{json, Value};
+
+    use crate::simulator::elasticsearch::server::FakeElasticsearch;
+
+    #[tokio::test]
+    async fn test_bulk_documents_are_recorded_per_index() {
+        let elasticsearch = FakeElasticsearch::start().unwrap();
+        let client = Client::new();
+
+        let info = client.get(elasticsearch.url()).send().await.unwrap();
+        assert_eq!(info.headers()["X-Elastic-Product"], "Elasticsearch");
+
+        let exists = client.head(format!("{}/_template/blocks", elasticsearch.url())).send().await.unwrap();
+        assert!(exists.status().is_success());
+
+        let body = concat!(
+            r#"{"index":{"_index":"blocks","_id":"aa"}}"#, "\n",
+            r#"{"nonce":1}"#, "\n",
+            r#"{"index":{"_index":"transactions","_id":"bb"}}"#, "\n",
+            r#"{"status":"success"}"#, "\n",
+        );
+        let response = client.post(format!("{}/_bulk", elasticsearch.url()))
+            .body(body)
+            .send()
+            .await
+            .unwrap()
+            .text()
+            .await
+            .unwrap();
+        let response: Value = serde_json::from_str(&response).unwrap();
+
+        assert_eq!(response["errors"], json!(false));
+        assert_eq!(elasticsearch.get_indexes(), vec!["blocks", "transactions"]);
+        assert_eq!(elasticsearch.get_document("blocks", "aa").unwrap().source, json!({"nonce": 1}));
+        assert_eq!(elasticsearch.get_documents("transactions").len(), 1);
+
+        elasticsearch.clear();
+        assert!(elasticsearch.get_indexes().is_empty());
+    }
+}
@@ -0,0 +1,209 @@
+use std::pin::Pin;
+use std::time::Duration;
+
+use base64::Engine;
+use base64::engine::general_purpose::STANDARD as BASE64;
+use futures_util::{stream, Stream, StreamExt};
+
+use crate::error::lib::LibError;
+use crate::error::simulator::SimulatorError;
+use crate::simulator::model::Simulator;
+use crate::simulator::outport::model::{OutportBlock, OutportEvent, OutportEventLog};
+use crate::utils::address::{bech32_to_public_key, public_key_to_bech32};
+
+const EVENTS_RECEPTION_TIMEOUT: Duration = Duration::from_millis(500);
+
+pub type ContractEventStream = Pin<Box<dyn Stream<Item = ContractEvent> + Send>>;
+
+#[derive(Clone, PartialEq, Debug)]
+pub struct ContractEvent {
+    pub tx_hash: String,
+    pub shard_id: u32,
+    pub address: String,
+    pub identifier: String,
+    pub topics: Vec<Vec<u8>>,
+    pub data: Vec<u8>,
+}
+
+impl ContractEvent {
+    fn from_outport_event_log(tx_hash: &str, shard_id: u32, event: &OutportEventLog) -> ContractEvent {
+        let address = BASE64.decode(&event.address)
+            .ok()
+            .and_then(|public_key| public_key_to_bech32(&public_key))
+            .unwrap_or_default();
+
+        ContractEvent {
+            tx_hash: tx_hash.to_string(),
+            shard_id,
+            address,
+            identifier: event.decoded_identifier(),
+            topics: event.decoded_topics(),
+            data: event.decoded_data(),
+        }
+    }
+}
+
+#[derive(Clone, PartialEq, Debug, Default)]
+pub struct EventFilter {
+    address: Option<String>,
+    identifier: Option<String>,
+    topics: Vec<(usize, Vec<u8>)>,
+}
+
+impl EventFilter {
+    pub fn new() -> EventFilter {
+        Self::default()
+    }
+
+    pub fn with_address(mut self, address: impl Into<String>) -> Self {
+        self.address = Some(address.into());
+
+        self
+    }
+
+    pub fn with_identifier(mut self, identifier: impl Into<String>) -> Self {
+        self.identifier = Some(identifier.into());
+
+        self
+    }
+
+    // The identifier is not counted as a topic.
+    pub fn with_topic(mut self, index: usize, value: impl Into<Vec<u8>>) -> Self {
+        self.topics.push((index, value.into()));
+
+        self
+    }
+
+    pub fn matches(&self, event: &ContractEvent) -> bool {
+        if let Some(address) = &self.address {
+            // Compared on the public keys, so an address that cannot be decoded never matches.
+            let Some(public_key) = bech32_to_public_key(address) else {
+                return false;
+            };
+
+            if bech32_to_public_key(&event.address) != Some(public_key) {
+                return false;
+            }
+        }
+
+        if self.identifier.as_ref().is_some_and(|identifier| *identifier != event.identifier) {
+            return false;
+        }
+
+        self.topics
+            .iter()
+            .all(|(index, value)| event.topics.get(*index) == Some(value))
+    }
+}
+
+impl Simulator {
+    pub fn subscribe_events(&self, filter: EventFilter) -> Result<ContractEventStream, LibError> {
+        let stream = self.outport_stream()?
+            .flat_map(move |event| {
+                let events: Vec<ContractEvent> = match event {
+                    OutportEvent::SaveBlock(block) => extract_contract_events(&block)
+                        .into_iter()
+                        .filter(|event| filter.matches(event))
+                        .collect(),
+                    _ => vec![],
+                };
+
+                stream::iter(events)
+            });
+
+        Ok(Box::pin(stream))
+    }
+
+    pub async fn wait_for_event(&self, filter: EventFilter, max_blocks: u64) -> Result<ContractEvent, LibError> {
+        let mut events = self.subscribe_events(filter)?;
+
+        for _ in 0..max_blocks {
+            self.generate_blocks(1).await?;
+
+            if let Ok(Some(event)) = tokio::time::timeout(EVENTS_RECEPTION_TIMEOUT, events.next()).await {
+                return Ok(event);
+            }
+        }
+
+        Err(SimulatorError::EventNotFound { max_blocks }.into())
+    }
+}
+
+fn extract_contract_events(block: &OutportBlock) -> Vec<ContractEvent> {
+    let Some(transaction_pool) = &block.transaction_pool else {
+        return vec![];
+    };
+
+    transaction_pool.logs
+        .iter()
+        .filter_map(|log_data| log_data.log.as_ref().map(|log| (&log_data.tx_hash, log)))
+        .flat_map(|(tx_hash, log)| {
+            log.events
+                .iter()
+                .map(|event| ContractEvent::from_outport_event_log(tx_hash, block.shard_id, event))
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use base64::Engine;
+    use base64::engine::general_purpose::STANDARD as BASE64;
+
+    use crate::simulator::events::{extract_contract_events, EventFilter};
+    use crate::simulator::outport::model::{OutportBlock, OutportEventLog, OutportLog, OutportLogData, OutportTransactionPool};
+    use crate::utils::address::bech32_to_public_key;
+
+    const PAIR_ADDRESS: &str = "erd1qqqqqqqqqqqqqpgqp699jngundfqw07d8jzkepucvpzush6k3wvqyc44rx";
+
+    fn swap_block() -> OutportBlock {
+        let event = OutportEventLog {
+            address: BASE64.encode(bech32_to_public_key(PAIR_ADDRESS).unwrap()),
+            identifier: BASE64.encode("swap"),
+            topics: vec![BASE64.encode("swap"), BASE64.encode("WEGLD-abcdef")],
+            data: BASE64.encode([1, 2, 3]),
+            additional_data: vec![],
+        };
+
+        OutportBlock {
+            shard_id: 1,
+            transaction_pool: Some(OutportTransactionPool {
+                logs: vec![
+                    OutportLogData { tx_hash: "aa".to_string(), log: None },
+                    OutportLogData {
+                        tx_hash: "bb".to_string(),
+                        log: Some(OutportLog { address: event.address.clone(), events: vec![event] }),
+                    },
+                ],
+                ..Default::default()
+            }),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_filter_contract_events() {
+        let events = extract_contract_events(&swap_block());
+
+        assert_eq!(events.len(), 1);
+
+        let event = &events[0];
+        assert_eq!(event.tx_hash, "bb");
+        assert_eq!(event.shard_id, 1);
+        assert_eq!(event.address, PAIR_ADDRESS);
+        assert_eq!(event.identifier, "swap");
+        assert_eq!(event.data, vec![1, 2, 3]);
+
+        assert!(EventFilter::new().matches(event));
+        assert!(EventFilter::new()
+            .with_address(PAIR_ADDRESS)
+            .with_identifier("swap")
+            .with_topic(1, "WEGLD-abcdef")
+            .matches(event));
+
+        assert!(!EventFilter::new().with_identifier("addLiquidity").matches(event));
+        assert!(!EventFilter::new().with_topic(1, "USDC-abcdef").matches(event));
+        assert!(!EventFilter::new().with_topic(2, "WEGLD-abcdef").matches(event));
+        assert!(!EventFilter::new().with_address("erd1qqqqqqqqqqqqqpgqeel2kumf0r8ffyhth7pqdujjat9nx0862jpsg2pqaq").matches(event));
+    }
+}
@@ -1,5 +1,25 @@
+pub(crate) mod api_routes;
+pub(crate) mod autogeneration;
+pub(crate) mod backend;
+pub(crate) mod batch;
+pub(crate) mod elasticsearch;
+pub(crate) mod events;
 pub(crate) mod model;
+mod node_config;
+mod network_config_cache;
+mod nodes_setup;
+mod nonces;
+mod observers;
+pub(crate) mod notifier;
 pub(crate) mod options;
+pub(crate) mod proxy_config;
+pub(crate) mod outport;
 pub(crate) mod requests;
+pub(crate) mod shard;
+pub(crate) mod staking;
 mod config;
-mod process;
\ No newline at end of file
+mod process;
+pub(crate) mod readiness;
+pub(crate) mod supervisor;
+mod transactions;
+mod validators;
\ No newline at end of file
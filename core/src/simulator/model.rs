@@ -1,40 +1,105 @@
 use std::collections::HashMap;
+use std::future::Future;
+use std::path::{Path, PathBuf};
+use std::pin::Pin;
 use std::sync::Arc;
-use std::time::{Duration, SystemTime, UNIX_EPOCH};
+use std::time::Duration;
 
-use nix::Error;
-use nix::sys::signal::{kill, Signal};
-use nix::unistd::Pid;
 use reqwest::Client;
 use tempfile::TempDir;
-use tokio::sync::Mutex;
+use tokio::sync::{broadcast, Mutex};
 
 use crate::error::lib::LibError;
+use crate::error::requests::about::AboutError;
+use crate::error::requests::block::BlockError;
+use crate::error::requests::force_epoch_change::ForceEpochChangeError;
 use crate::error::requests::generate_blocks::GenerateBlocksError;
+use crate::error::requests::hyperblock::HyperblockError;
 use crate::error::requests::initial_wallets::InitialWalletsError;
+use crate::error::requests::network_config::NetworkConfigError;
+use crate::error::requests::network_economics::NetworkEconomicsError;
+use crate::error::requests::network_status::NetworkStatusError;
 use crate::error::requests::set_address_keys::SetAddressKeysError;
 use crate::error::requests::set_state::SetStateError;
+use crate::error::requests::transaction::TransactionError;
 use crate::error::simulator::SimulatorError;
+use crate::simulator::autogeneration::{Autogeneration, AutogenerationStatus, check_interval as check_autogeneration_interval};
+use crate::simulator::backend::{SimulatorBackend, SimulatorBackendKind};
 use crate::simulator::config::SimulatorConfig;
+use crate::simulator::node_config::{apply_node_config_overrides, ElasticsearchConnectorOverrides, NodeConfigOverrides};
+use crate::simulator::network_config_cache::NetworkConfigCache;
+use crate::simulator::nonces::NonceManager;
+use crate::simulator::notifier::model::NotifierEvents;
+use crate::simulator::notifier::server::NotifierReceiver;
+use crate::simulator::outport::server::{OutportServer, OutportStream};
 use crate::simulator::process::SimulatorProcess;
+use crate::simulator::proxy_config::apply_proxy_options;
+use crate::simulator::readiness::{check_initial_epoch, wait_to_be_ready};
+use crate::simulator::requests::about::{About, AboutResponse};
+use crate::simulator::requests::block::{Block, BlockResponse, Hyperblock, HyperblockResponse, ProducedBlock};
+use crate::simulator::requests::force_epoch_change::ForceEpochChangeResponse;
 use crate::simulator::requests::generate_blocks::GenerateBlocksResponse;
 use crate::simulator::requests::initial_wallets::{InitialWallets, InitialWalletsResponse};
+use crate::simulator::requests::network_config::{NetworkConfig, NetworkConfigResponse};
+use crate::simulator::requests::network_economics::{NetworkEconomics, NetworkEconomicsResponse};
+use crate::simulator::requests::network_status::{NetworkStatus, NetworkStatusResponse};
 use crate::simulator::requests::set_address_keys::SetAddressKeysResponse;
-use crate::simulator::requests::set_state::SetStateAddress;
+use crate::simulator::requests::set_state::{SetStateAddress, SetStateResponse};
+use crate::simulator::requests::transaction::{TransactionOnNetwork, TransactionResponse};
+use crate::simulator::shard::ShardId;
+use crate::simulator::supervisor::{SimulatorEvent, SimulatorSupervisor};
 use crate::SimulatorOptions;
+use crate::utils::binary::SimulatorBinary;
 use crate::utils::fs::get_temp_dir;
-use crate::utils::process::{prepare_temp_dir_for_simulator, spawn_simulator_process};
+use crate::utils::process::run_blocking;
+
+const EVENTS_CHANNEL_CAPACITY: usize = 4096;
+
+#[derive(Clone)]
+pub(crate) struct SimulatorInstance {
+    process_id: u32,
+    options: SimulatorOptions,
+    backend: Arc<dyn SimulatorBackend>,
+    supervisor: Arc<SimulatorSupervisor>,
+}
+
+type SimulatorInstanceMutex = Arc<Mutex<Option<SimulatorInstance>>>;
+
+// Shared with the supervisor, which restarts the simulator with it.
+#[derive(Clone)]
+struct SimulatorContext {
+    instance: SimulatorInstanceMutex,
+    tempdir: Arc<TempDir>,
+    binary: Arc<Mutex<Option<SimulatorBinary>>>,
+    events: broadcast::Sender<SimulatorEvent>,
+    autogeneration: Autogeneration,
+    nonces: NonceManager,
+    network_config: NetworkConfigCache,
+    outport: OutportServer,
+    notifier: NotifierReceiver,
+}
 
 pub struct Simulator {
-    process_id_and_options: Arc<Mutex<Option<(u32, SimulatorOptions)>>>,
+    instance: SimulatorInstanceMutex,
     tempdir: Arc<TempDir>,
+    binary: Arc<Mutex<Option<SimulatorBinary>>>,
+    events: broadcast::Sender<SimulatorEvent>,
+    autogeneration: Autogeneration,
+    nonces: NonceManager,
+    network_config: NetworkConfigCache,
+    outport: OutportServer,
+    notifier: NotifierReceiver,
 }
 
 impl Drop for Simulator {
     fn drop(&mut self) {
-        let process_id_and_options_mutex = self.process_id_and_options.clone();
+        self.autogeneration.stop();
+        self.outport.stop();
+        self.notifier.stop();
+
+        let instance_mutex = self.instance.clone();
         tokio::spawn(async move {
-            let _ = kill_simulator_process(process_id_and_options_mutex).await; // We ignore the result bc in the drop method we want to kill the process if it exists.
+            let _ = kill_simulator_process(instance_mutex).await; // We ignore the result bc in the drop method we want to kill the process if it exists.
         });
     }
 }
@@ -43,38 +108,55 @@ impl Simulator {
     pub fn new() -> Result<Simulator, LibError> {
         let tempdir = get_temp_dir()?;
 
-        prepare_temp_dir_for_simulator(tempdir.path())?;
-
         let simulator = Simulator {
-            process_id_and_options: Arc::new(Mutex::new(None)),
+            instance: Arc::new(Mutex::new(None)),
             tempdir: tempdir.into(),
+            binary: Arc::new(Mutex::new(None)),
+            events: broadcast::channel(EVENTS_CHANNEL_CAPACITY).0,
+            autogeneration: Autogeneration::default(),
+            nonces: NonceManager::default(),
+            network_config: NetworkConfigCache::default(),
+            outport: OutportServer::default(),
+            notifier: NotifierReceiver::default(),
         };
 
         Ok(simulator)
     }
 
     pub async fn start(&self, options: SimulatorOptions) -> Result<SimulatorProcess, LibError> {
-        let _ = self.kill().await; // We ignore the result bc in the start method we want to kill the old process if it exists.
+        let max_restarts = options.max_restarts;
+
+        start_simulator(self.get_context(), options, max_restarts).await
+    }
+
+    pub fn subscribe_to_events(&self) -> broadcast::Receiver<SimulatorEvent> {
+        self.events.subscribe()
+    }
 
-        let cli_args: Vec<String> = options.to_cli_args();
-        let config = SimulatorConfig::from(options);
-        let child = spawn_simulator_process(self.tempdir.path(), &config.get_toml_content()?, &cli_args)?;
-        wait_to_be_ready(options.server_port, Duration::from_secs(10)).await?;
+    pub fn outport_stream(&self) -> Result<OutportStream, LibError> {
+        let Some(stream) = self.outport.subscribe() else {
+            return Err(SimulatorError::OutportStreamNotEnabled.into());
+        };
 
-        let mut opt_process_and_options = self.process_id_and_options.lock().await;
-        *opt_process_and_options = Some((child.id(), options));
-        drop(opt_process_and_options);
+        Ok(stream)
+    }
 
-        self.generate_epochs(1).await?;
+    pub fn get_notifier_events(&self) -> Result<NotifierEvents, LibError> {
+        if !self.notifier.is_running() {
+            return Err(SimulatorError::EventNotifierNotEnabled.into());
+        }
 
-        if let Some(block_autogenerate_duration) = options.block_autogenerate_duration {
-            let process_id_and_options_mutex = self.process_id_and_options.clone();
-            tokio::spawn(async move {
-                autogenerate_blocks(block_autogenerate_duration, process_id_and_options_mutex, options.server_port).await
-            });
+        Ok(self.notifier.get_events())
+    }
+
+    pub fn clear_notifier_events(&self) -> Result<(), LibError> {
+        if !self.notifier.is_running() {
+            return Err(SimulatorError::EventNotifierNotEnabled.into());
         }
 
-        Ok(SimulatorProcess(child))
+        self.notifier.clear_events();
+
+        Ok(())
     }
 
     pub async fn generate_blocks(&self, num_blocks: u64) -> Result<(), LibError> {
@@ -83,6 +165,101 @@ impl Simulator {
         generate_blocks(options.server_port, num_blocks).await
     }
 
+    // The produced blocks may include some generated by the autogeneration.
+    pub async fn generate_blocks_and_get_produced(&self, num_blocks: u64) -> Result<Vec<ProducedBlock>, LibError> {
+        let (_, options) = self.get_process_id_and_options().await?;
+
+        let shards: Vec<ShardId> = (0..options.num_of_shards as u32).map(ShardId::Shard).chain([ShardId::Metachain]).collect();
+
+        let mut nonces_before = Vec::with_capacity(shards.len());
+        for shard in &shards {
+            nonces_before.push(get_network_status(options.server_port, *shard).await?.nonce);
+        }
+
+        generate_blocks(options.server_port, num_blocks).await?;
+
+        let mut produced_blocks = vec![];
+        for (shard, nonce_before) in shards.into_iter().zip(nonces_before) {
+            let nonce_after = get_network_status(options.server_port, shard).await?.nonce;
+
+            for nonce in nonce_before + 1..=nonce_after {
+                let url = format!("http://localhost:{}/block/{}/by-nonce/{}", options.server_port, shard.as_u32(), nonce);
+                let block = get_block(url).await?;
+
+                produced_blocks.push(ProducedBlock { shard: shard.as_u32(), nonce, hash: block.hash });
+            }
+        }
+
+        Ok(produced_blocks)
+    }
+
+    pub async fn get_block_by_nonce(&self, shard: ShardId, nonce: u64) -> Result<Block, LibError> {
+        let (_, options) = self.get_process_id_and_options().await?;
+
+        let url = format!("http://localhost:{}/block/{}/by-nonce/{}?withTxs=true", options.server_port, shard.as_u32(), nonce);
+
+        get_block(url).await
+    }
+
+    pub async fn get_block_by_hash(&self, shard: ShardId, hash: &str) -> Result<Block, LibError> {
+        let (_, options) = self.get_process_id_and_options().await?;
+
+        let url = format!("http://localhost:{}/block/{}/by-hash/{}?withTxs=true", options.server_port, shard.as_u32(), hash);
+
+        get_block(url).await
+    }
+
+    pub async fn get_hyperblock_by_nonce(&self, nonce: u64) -> Result<Hyperblock, LibError> {
+        let (_, options) = self.get_process_id_and_options().await?;
+
+        get_hyperblock_by_nonce(options.server_port, nonce).await
+    }
+
+    pub async fn get_latest_hyperblock(&self) -> Result<Hyperblock, LibError> {
+        let (_, options) = self.get_process_id_and_options().await?;
+
+        // The hyperblock of the current nonce might not be available yet, the highest final one always is.
+        let status = get_network_status(options.server_port, ShardId::Metachain).await?;
+
+        get_hyperblock_by_nonce(options.server_port, status.highest_final_nonce).await
+    }
+
+    pub async fn get_transaction(&self, hash: &str) -> Result<TransactionOnNetwork, LibError> {
+        let (_, options) = self.get_process_id_and_options().await?;
+
+        let url = format!("http://localhost:{}/transaction/{}?withResults=true", options.server_port, hash);
+
+        let Ok(response) = Client::new()
+            .get(&url)
+            .send()
+            .await
+            else {
+                return Err(TransactionError::CannotSendRequest { url }.into());
+            };
+
+        if !response.status().is_success() {
+            return Err(TransactionError::ResponseStatusIsNotSuccessful { url, status: response.status().as_u16() }.into());
+        }
+
+        let Ok(text) = response.text().await else {
+            return Err(TransactionError::CannotGetTextFromTheResponse { url }.into());
+        };
+
+        let Ok(result) = serde_json::from_str::<TransactionResponse>(&text) else {
+            return Err(TransactionError::FailedToParseTheResponse { url, response: text }.into());
+        };
+
+        if result.code != "successful" {
+            return Err(TransactionError::ResponseCodeIsNotSuccessful { url, code: result.code }.into());
+        }
+
+        let Some(data) = result.data else {
+            return Err(TransactionError::ResponseCodeIsNotSuccessful { url, code: result.code }.into());
+        };
+
+        Ok(data.transaction)
+    }
+
     pub async fn get_initial_wallets(&self) -> Result<InitialWallets, LibError> {
         let (_, options) = self.get_process_id_and_options().await?;
 
@@ -119,58 +296,131 @@ impl Simulator {
         Ok(data)
     }
 
-    pub async fn generate_epochs(&self, num_epochs: u64) -> Result<(), LibError> {
+    pub async fn get_network_config(&self) -> Result<NetworkConfig, LibError> {
+        let (_, options) = self.get_process_id_and_options().await?;
+
+        get_network_config(options.server_port).await
+    }
+
+    pub async fn get_network_economics(&self) -> Result<NetworkEconomics, LibError> {
         let (_, options) = self.get_process_id_and_options().await?;
 
-        let blocks_to_generate = (options.rounds_per_epoch + 1) * num_epochs;
+        get_network_economics(options.server_port).await
+    }
+
+    pub async fn get_network_status(&self, shard: ShardId) -> Result<NetworkStatus, LibError> {
+        let (_, options) = self.get_process_id_and_options().await?;
 
-        self.generate_blocks(blocks_to_generate).await
+        get_network_status(options.server_port, shard).await
     }
 
-    pub async fn set_address_keys(&self, address: &str, keys: &HashMap<String, String>) -> Result<(), LibError> {
+    pub async fn get_about(&self) -> Result<About, LibError> {
         let (_, options) = self.get_process_id_and_options().await?;
 
-        let url = format!("http://localhost:{}/simulator/address/{}/set-state", options.server_port, address);
+        let url = format!("http://localhost:{}/about", options.server_port);
 
-        let Ok(body) = serde_json::to_string(keys) else {
-            return Err(SetAddressKeysError::CannotConvertKeysAsJSON { url: url, keys: keys.clone() }.into())
+        let Ok(response) = Client::new()
+            .get(&url)
+            .send()
+            .await
+            else {
+                return Err(AboutError::CannotSendRequest { url }.into());
+            };
+
+        if !response.status().is_success() {
+            return Err(AboutError::ResponseStatusIsNotSuccessful { url, status: response.status().as_u16() }.into());
+        }
+
+        let Ok(text) = response.text().await else {
+            return Err(AboutError::CannotGetTextFromTheResponse { url }.into());
+        };
+
+        let Ok(result) = serde_json::from_str::<AboutResponse>(&text) else {
+            return Err(AboutError::FailedToParseTheResponse { url, response: text }.into());
+        };
+
+        if result.code != "successful" {
+            return Err(AboutError::ResponseCodeIsNotSuccessful { url, code: result.code }.into());
+        }
+
+        let Some(data) = result.data else {
+            return Err(AboutError::ResponseCodeIsNotSuccessful { url, code: result.code }.into());
         };
 
+        Ok(data)
+    }
+
+    pub async fn generate_epochs(&self, num_epochs: u64) -> Result<(), LibError> {
+        let (_, options) = self.get_process_id_and_options().await?;
+
+        let status = get_network_status(options.server_port, ShardId::Metachain).await?;
+
+        generate_blocks_until_epoch_reached(options.server_port, status.epoch_number + num_epochs).await
+    }
+
+    pub async fn generate_blocks_until_epoch_reached(&self, epoch: u64) -> Result<(), LibError> {
+        let (_, options) = self.get_process_id_and_options().await?;
+
+        generate_blocks_until_epoch_reached(options.server_port, epoch).await
+    }
+
+    pub async fn generate_until_round(&self, round: u64) -> Result<(), LibError> {
+        let (_, options) = self.get_process_id_and_options().await?;
+
+        generate_until_round(options.server_port, round).await
+    }
+
+    // Rounded up to a whole round.
+    pub async fn advance_time(&self, duration: Duration) -> Result<(), LibError> {
+        let (_, options) = self.get_process_id_and_options().await?;
+
+        let config = get_network_config(options.server_port).await?;
+        let status = get_network_status(options.server_port, ShardId::Metachain).await?;
+
+        let rounds = get_rounds_for_duration(duration, config.round_duration);
+
+        generate_until_round(options.server_port, status.current_round + rounds).await
+    }
+
+    pub async fn force_epoch_change(&self) -> Result<(), LibError> {
+        let (_, options) = self.get_process_id_and_options().await?;
+
+        let url = format!("http://localhost:{}/simulator/force-epoch-change", options.server_port);
+
         let Ok(response) = Client::new()
             .post(&url)
-            .body(body)
             .send()
             .await
             else {
-                return Err(SetAddressKeysError::CannotGetTextFromTheResponse { url }.into());
+                return Err(ForceEpochChangeError::CannotSendRequest { url }.into());
             };
 
         if !response.status().is_success() {
-            return Err(SetAddressKeysError::ResponseStatusIsNotSuccessful { url, status: response.status().as_u16() }.into());
+            return Err(ForceEpochChangeError::ResponseStatusIsNotSuccessful { url, status: response.status().as_u16() }.into());
         }
 
         let Ok(text) = response.text().await else {
-            return Err(SetAddressKeysError::CannotGetTextFromTheResponse { url }.into());
+            return Err(ForceEpochChangeError::CannotGetTextFromTheResponse { url }.into());
         };
 
-        let Ok(result) = serde_json::from_str::<SetAddressKeysResponse>(&text) else {
-            return Err(SetAddressKeysError::FailedToParseTheResponse { url, response: text }.into());
+        let Ok(result) = serde_json::from_str::<ForceEpochChangeResponse>(&text) else {
+            return Err(ForceEpochChangeError::FailedToParseTheResponse { url, response: text }.into());
         };
 
         if result.code != "successful" {
-            return Err(SetAddressKeysError::ResponseCodeIsNotSuccessful { url, code: result.code }.into());
+            return Err(ForceEpochChangeError::ResponseCodeIsNotSuccessful { url, code: result.code }.into());
         }
 
         Ok(())
     }
 
-    pub async fn set_state(&self, state: &[SetStateAddress]) -> Result<(), LibError> {
+    pub async fn set_address_keys(&self, address: &str, keys: &HashMap<String, String>) -> Result<(), LibError> {
         let (_, options) = self.get_process_id_and_options().await?;
 
-        let url = format!("http://localhost:{}/simulator/set-state", options.server_port);
+        let url = format!("http://localhost:{}/simulator/address/{}/set-state", options.server_port, address);
 
-        let Ok(body) = serde_json::to_string(state) else {
-            return Err(SetStateError::CannotConvertStateAsJSON { url, state: state.to_vec() }.into())
+        let Ok(body) = serde_json::to_string(keys) else {
+            return Err(SetAddressKeysError::CannotConvertKeysAsJSON { url, keys: keys.clone() }.into())
         };
 
         let Ok(response) = Client::new()
@@ -179,81 +429,225 @@ impl Simulator {
             .send()
             .await
             else {
-                return Err(SetStateError::CannotGetTextFromTheResponse { url }.into());
+                return Err(SetAddressKeysError::CannotGetTextFromTheResponse { url }.into());
             };
 
         if !response.status().is_success() {
-            return Err(SetStateError::ResponseStatusIsNotSuccessful { url, status: response.status().as_u16() }.into());
+            return Err(SetAddressKeysError::ResponseStatusIsNotSuccessful { url, status: response.status().as_u16() }.into());
         }
 
         let Ok(text) = response.text().await else {
-            return Err(SetStateError::CannotGetTextFromTheResponse { url }.into());
+            return Err(SetAddressKeysError::CannotGetTextFromTheResponse { url }.into());
         };
 
         let Ok(result) = serde_json::from_str::<SetAddressKeysResponse>(&text) else {
-            return Err(SetStateError::FailedToParseTheResponse { url, response: text }.into());
+            return Err(SetAddressKeysError::FailedToParseTheResponse { url, response: text }.into());
         };
 
         if result.code != "successful" {
-            return Err(SetStateError::ResponseCodeIsNotSuccessful { url, code: result.code }.into());
+            return Err(SetAddressKeysError::ResponseCodeIsNotSuccessful { url, code: result.code }.into());
         }
 
+        self.nonces.invalidate(address).await;
+
         Ok(())
     }
 
-    pub async fn autogenerate_blocks(&self, each: Duration) -> Result<(), LibError> {
+    pub async fn set_state(&self, state: &[SetStateAddress]) -> Result<(), LibError> {
         let (_, options) = self.get_process_id_and_options().await?;
-        autogenerate_blocks(each, self.process_id_and_options.clone(), options.server_port).await?;
+
+        set_state(options.server_port, state).await?;
+
+        for address in state {
+            match &address.address {
+                Some(address) => self.nonces.invalidate(address).await,
+                None => self.nonces.invalidate_all().await,
+            }
+        }
 
         Ok(())
     }
 
-    async fn get_process_id_and_options(&self) -> Result<(u32, SimulatorOptions), LibError> {
-        let opt_process_and_options = self.process_id_and_options.lock().await;
+    pub async fn get_simulator_binary(&self) -> Option<SimulatorBinary> {
+        self.binary.lock().await.clone()
+    }
+
+    pub async fn get_logs_path(&self) -> Result<PathBuf, LibError> {
+        let opt_instance = self.instance.lock().await;
 
-        let Some((process_id, options)) = opt_process_and_options.as_ref() else {
+        let Some(instance) = opt_instance.as_ref() else {
             return Err(SimulatorError::ProcessNotStarted.into());
         };
 
-        if !is_process_running(*process_id) {
-            return Err(SimulatorError::ProcessAlreadyFinished.into());
+        Ok(self.tempdir.path().join(&instance.options.logs_path))
+    }
+
+    // Replaces any previous autogeneration.
+    pub async fn start_autogeneration(&self, interval: Duration, blocks_per_tick: u64) -> Result<(), LibError> {
+        self.get_process_id_and_options().await?;
+
+        start_autogeneration(&self.get_context(), interval, blocks_per_tick)
+    }
+
+    pub fn pause_autogeneration(&self) -> Result<(), LibError> {
+        if !self.autogeneration.set_paused(true) {
+            return Err(SimulatorError::AutogenerationNotStarted.into());
+        }
+
+        Ok(())
+    }
+
+    pub fn resume_autogeneration(&self) -> Result<(), LibError> {
+        if !self.autogeneration.set_paused(false) {
+            return Err(SimulatorError::AutogenerationNotStarted.into());
         }
 
-        Ok((*process_id, *options))
+        Ok(())
+    }
+
+    pub fn stop_autogeneration(&self) {
+        self.autogeneration.stop();
+    }
+
+    pub fn get_autogeneration_status(&self) -> AutogenerationStatus {
+        self.autogeneration.get_status()
+    }
+
+    pub(crate) async fn get_process_id_and_options(&self) -> Result<(u32, SimulatorOptions), LibError> {
+        get_simulator_process_id_and_options(self.instance.clone()).await
+    }
+
+    pub(crate) fn get_tempdir_path(&self) -> &Path {
+        self.tempdir.path()
     }
 
-    async fn kill(&self) -> Result<(), LibError> {
-        kill_simulator_process(self.process_id_and_options.clone()).await
+    pub(crate) fn get_nonce_manager(&self) -> &NonceManager {
+        &self.nonces
+    }
+
+    pub(crate) async fn get_cached_network_config(&self, server_port: u16) -> Result<NetworkConfig, LibError> {
+        self.network_config.get(|| get_network_config(server_port)).await
+    }
+
+    fn get_context(&self) -> SimulatorContext {
+        SimulatorContext {
+            instance: self.instance.clone(),
+            tempdir: self.tempdir.clone(),
+            binary: self.binary.clone(),
+            events: self.events.clone(),
+            autogeneration: self.autogeneration.clone(),
+            nonces: self.nonces.clone(),
+            network_config: self.network_config.clone(),
+            outport: self.outport.clone(),
+            notifier: self.notifier.clone(),
+        }
     }
 }
 
-async fn wait_to_be_ready(server_port: u16, timeout: Duration) -> Result<(), LibError> {
-    let start_time = SystemTime::now().duration_since(UNIX_EPOCH).unwrap();
+// Boxed because a restart started by the supervisor calls this function again.
+fn start_simulator(context: SimulatorContext, options: SimulatorOptions, remaining_restarts: u32) -> Pin<Box<dyn Future<Output = Result<SimulatorProcess, LibError>> + Send>> {
+    Box::pin(async move {
+        options.nodes_setup.validate(options.num_of_shards)?;
+        check_backend_supports_options(&options)?;
+        if let Some(block_autogenerate_duration) = options.block_autogenerate_duration {
+            check_autogeneration_interval(block_autogenerate_duration)?;
+        }
+
+        let _ = kill_simulator_process(context.instance.clone()).await; // We ignore the result bc in the start method we want to kill the old process if it exists.
+        context.nonces.invalidate_all().await;
+        context.network_config.invalidate().await;
 
-    let url = format!("http://localhost:{}/about", server_port);
+        let backend = options.backend.create_backend();
 
-    loop {
-        let current_time = SystemTime::now().duration_since(UNIX_EPOCH).unwrap();
-        if current_time > start_time + timeout {
-            return Err(SimulatorError::TimedOutWhileWaitingToBeReady.into())
+        let prepare_backend = backend.clone();
+        let working_dir = context.tempdir.path().to_path_buf();
+        let prepare_options = options.clone();
+        let binary = run_blocking(move || prepare_backend.prepare(&working_dir, &prepare_options)).await?;
+        *context.binary.lock().await = binary;
+
+        let mut node_config_overrides = NodeConfigOverrides {
+            api_routes: options.node_api_routes.clone(),
+            ..Default::default()
+        };
+        if options.outport_stream {
+            node_config_overrides.outport_url = Some(context.outport.start()?);
+        }
+        if options.event_notifier {
+            node_config_overrides.event_notifier_url = Some(context.notifier.start(options.event_notifier_credentials.as_ref())?);
+            node_config_overrides.event_notifier_credentials = options.event_notifier_credentials.clone();
         }
+        if let Some(elasticsearch_url) = &options.elasticsearch_url {
+            node_config_overrides.elasticsearch = Some(ElasticsearchConnectorOverrides {
+                url: elasticsearch_url.clone(),
+                indexes: options.elasticsearch_indexes.clone(),
+                credentials: options.elasticsearch_credentials.clone(),
+                bulk_request_max_size_in_bytes: options.elasticsearch_bulk_request_max_size_in_bytes,
+            });
+        }
+        let overrides_working_dir = context.tempdir.path().to_path_buf();
+        let proxy_options = options.proxy.clone();
+        run_blocking(move || {
+            apply_node_config_overrides(&overrides_working_dir, &node_config_overrides)?;
+            apply_proxy_options(&overrides_working_dir, &proxy_options)
+        }).await?;
+
+        let config = SimulatorConfig::from(&options);
+        let child = backend.spawn(context.tempdir.path(), &config.get_toml_content()?, &options)?;
+        let process_id = child.id();
+
+        // Subscribing before the supervisor starts reading the output, so no line is missed.
+        let process = SimulatorProcess { events: context.events.subscribe() };
+        let supervisor = SimulatorSupervisor::start(child, context.events.clone());
+
+        let mut opt_instance = context.instance.lock().await;
+        *opt_instance = Some(SimulatorInstance {
+            process_id,
+            options: options.clone(),
+            backend,
+            supervisor: supervisor.clone(),
+        });
+        drop(opt_instance);
 
-        let response = reqwest::get(&url).await;
+        wait_to_be_ready(&supervisor, options.server_port, options.num_of_shards, &options.readiness_policy).await?;
 
-        if response.is_ok() {
-            let response = response.unwrap();
-            if response.status().is_success() {
-                break
-            }
+        generate_blocks(options.server_port, options.rounds_per_epoch + 1).await?;
+
+        if options.readiness_policy.check_initial_epoch {
+            check_initial_epoch(options.server_port, 1).await?;
+        }
+
+        if !options.initial_state.is_empty() {
+            set_state(options.server_port, &options.initial_state).await?;
+        }
+
+        if let Some(block_autogenerate_duration) = options.block_autogenerate_duration {
+            start_autogeneration(&context, block_autogenerate_duration, options.blocks_per_autogeneration_tick)?;
+        }
+
+        if remaining_restarts > 0 {
+            tokio::spawn(restart_on_crash(context, supervisor, options, remaining_restarts));
         }
 
-        tokio::time::sleep(Duration::from_millis(10)).await
+        Ok(process)
+    })
+}
+
+async fn restart_on_crash(context: SimulatorContext, supervisor: Arc<SimulatorSupervisor>, options: SimulatorOptions, remaining_restarts: u32) {
+    supervisor.wait_for_exit().await;
+
+    if supervisor.is_stopping() {
+        return;
     }
 
-    Ok(())
+    let event = match start_simulator(context.clone(), options, remaining_restarts - 1).await {
+        Ok(_) => SimulatorEvent::Restarted,
+        Err(error) => SimulatorEvent::RestartFailed(error),
+    };
+
+    let _ = context.events.send(event); // There might be no subscriber.
 }
 
-async fn generate_blocks(server_port: u16, num_blocks: u64) -> Result<(), LibError> {
+pub(crate) async fn generate_blocks(server_port: u16, num_blocks: u64) -> Result<(), LibError> {
     let url = format!("http://localhost:{}/simulator/generate-blocks/{}", server_port, num_blocks);
 
     let Ok(response) = Client::new()
@@ -283,54 +677,355 @@ async fn generate_blocks(server_port: u16, num_blocks: u64) -> Result<(), LibErr
     Ok(())
 }
 
-async fn autogenerate_blocks(each: Duration, process_and_options_mutex: Arc<Mutex<Option<(u32, SimulatorOptions)>>>, server_port: u16) -> Result<(), LibError> {
-    loop {
-        let is_process_active = {
-            let process_and_options = process_and_options_mutex.lock().await;
+async fn set_state(server_port: u16, state: &[SetStateAddress]) -> Result<(), LibError> {
+    let url = format!("http://localhost:{}/simulator/set-state", server_port);
+
+    let Ok(body) = serde_json::to_string(state) else {
+        return Err(SetStateError::CannotConvertStateAsJSON { url, state: state.to_vec() }.into())
+    };
 
-            process_and_options.is_some()
+    let Ok(response) = Client::new()
+        .post(&url)
+        .body(body)
+        .send()
+        .await
+        else {
+            return Err(SetStateError::CannotGetTextFromTheResponse { url }.into());
         };
 
-        if !is_process_active {
-            break
-        }
+    if !response.status().is_success() {
+        return Err(SetStateError::ResponseStatusIsNotSuccessful { url, status: response.status().as_u16() }.into());
+    }
 
-        generate_blocks(server_port, 1).await?;
+    let Ok(text) = response.text().await else {
+        return Err(SetStateError::CannotGetTextFromTheResponse { url }.into());
+    };
+
+    let Ok(result) = serde_json::from_str::<SetStateResponse>(&text) else {
+        return Err(SetStateError::FailedToParseTheResponse { url, response: text }.into());
+    };
 
-        tokio::time::sleep(each).await;
+    if result.code != "successful" {
+        return Err(SetStateError::ResponseCodeIsNotSuccessful { url, code: result.code }.into());
     }
 
     Ok(())
 }
 
-async fn kill_simulator_process(process_and_options_mutex: Arc<Mutex<Option<(u32, SimulatorOptions)>>>) -> Result<(), LibError> {
-    let (process_id, _) = get_simulator_process_id_and_options(process_and_options_mutex.clone()).await?;
+pub(crate) async fn get_network_status(server_port: u16, shard: ShardId) -> Result<NetworkStatus, LibError> {
+    let url = format!("http://localhost:{}/network/status/{}", server_port, shard.as_u32());
+
+    let Ok(response) = Client::new()
+        .get(&url)
+        .send()
+        .await
+        else {
+            return Err(NetworkStatusError::CannotSendRequest { url }.into());
+        };
+
+    if !response.status().is_success() {
+        return Err(NetworkStatusError::ResponseStatusIsNotSuccessful { url, status: response.status().as_u16() }.into());
+    }
+
+    let Ok(text) = response.text().await else {
+        return Err(NetworkStatusError::CannotGetTextFromTheResponse { url }.into());
+    };
+
+    let Ok(result) = serde_json::from_str::<NetworkStatusResponse>(&text) else {
+        return Err(NetworkStatusError::FailedToParseTheResponse { url, response: text }.into());
+    };
+
+    if result.code != "successful" {
+        return Err(NetworkStatusError::ResponseCodeIsNotSuccessful { url, code: result.code }.into());
+    }
+
+    let Some(data) = result.data else {
+        return Err(NetworkStatusError::ResponseCodeIsNotSuccessful { url, code: result.code }.into());
+    };
+
+    Ok(data.status)
+}
+
+pub(crate) async fn get_network_config(server_port: u16) -> Result<NetworkConfig, LibError> {
+    let url = format!("http://localhost:{}/network/config", server_port);
+
+    let Ok(response) = Client::new()
+        .get(&url)
+        .send()
+        .await
+        else {
+            return Err(NetworkConfigError::CannotSendRequest { url }.into());
+        };
+
+    if !response.status().is_success() {
+        return Err(NetworkConfigError::ResponseStatusIsNotSuccessful { url, status: response.status().as_u16() }.into());
+    }
+
+    let Ok(text) = response.text().await else {
+        return Err(NetworkConfigError::CannotGetTextFromTheResponse { url }.into());
+    };
+
+    let Ok(result) = serde_json::from_str::<NetworkConfigResponse>(&text) else {
+        return Err(NetworkConfigError::FailedToParseTheResponse { url, response: text }.into());
+    };
+
+    if result.code != "successful" {
+        return Err(NetworkConfigError::ResponseCodeIsNotSuccessful { url, code: result.code }.into());
+    }
+
+    let Some(data) = result.data else {
+        return Err(NetworkConfigError::ResponseCodeIsNotSuccessful { url, code: result.code }.into());
+    };
+
+    Ok(data.config)
+}
+
+async fn get_block(url: String) -> Result<Block, LibError> {
+    let Ok(response) = Client::new()
+        .get(&url)
+        .send()
+        .await
+        else {
+            return Err(BlockError::CannotSendRequest { url }.into());
+        };
+
+    if !response.status().is_success() {
+        return Err(BlockError::ResponseStatusIsNotSuccessful { url, status: response.status().as_u16() }.into());
+    }
+
+    let Ok(text) = response.text().await else {
+        return Err(BlockError::CannotGetTextFromTheResponse { url }.into());
+    };
+
+    let Ok(result) = serde_json::from_str::<BlockResponse>(&text) else {
+        return Err(BlockError::FailedToParseTheResponse { url, response: text }.into());
+    };
+
+    if result.code != "successful" {
+        return Err(BlockError::ResponseCodeIsNotSuccessful { url, code: result.code }.into());
+    }
+
+    let Some(data) = result.data else {
+        return Err(BlockError::ResponseCodeIsNotSuccessful { url, code: result.code }.into());
+    };
+
+    Ok(data.block)
+}
+
+async fn get_hyperblock_by_nonce(server_port: u16, nonce: u64) -> Result<Hyperblock, LibError> {
+    let url = format!("http://localhost:{}/hyperblock/by-nonce/{}", server_port, nonce);
+
+    let Ok(response) = Client::new()
+        .get(&url)
+        .send()
+        .await
+        else {
+            return Err(HyperblockError::CannotSendRequest { url }.into());
+        };
+
+    if !response.status().is_success() {
+        return Err(HyperblockError::ResponseStatusIsNotSuccessful { url, status: response.status().as_u16() }.into());
+    }
+
+    let Ok(text) = response.text().await else {
+        return Err(HyperblockError::CannotGetTextFromTheResponse { url }.into());
+    };
+
+    let Ok(result) = serde_json::from_str::<HyperblockResponse>(&text) else {
+        return Err(HyperblockError::FailedToParseTheResponse { url, response: text }.into());
+    };
+
+    if result.code != "successful" {
+        return Err(HyperblockError::ResponseCodeIsNotSuccessful { url, code: result.code }.into());
+    }
+
+    let Some(data) = result.data else {
+        return Err(HyperblockError::ResponseCodeIsNotSuccessful { url, code: result.code }.into());
+    };
+
+    Ok(data.hyperblock)
+}
+
+async fn get_network_economics(server_port: u16) -> Result<NetworkEconomics, LibError> {
+    let url = format!("http://localhost:{}/network/economics", server_port);
+
+    let Ok(response) = Client::new()
+        .get(&url)
+        .send()
+        .await
+        else {
+            return Err(NetworkEconomicsError::CannotSendRequest { url }.into());
+        };
+
+    if !response.status().is_success() {
+        return Err(NetworkEconomicsError::ResponseStatusIsNotSuccessful { url, status: response.status().as_u16() }.into());
+    }
+
+    let Ok(text) = response.text().await else {
+        return Err(NetworkEconomicsError::CannotGetTextFromTheResponse { url }.into());
+    };
+
+    let Ok(result) = serde_json::from_str::<NetworkEconomicsResponse>(&text) else {
+        return Err(NetworkEconomicsError::FailedToParseTheResponse { url, response: text }.into());
+    };
+
+    if result.code != "successful" {
+        return Err(NetworkEconomicsError::ResponseCodeIsNotSuccessful { url, code: result.code }.into());
+    }
+
+    let Some(data) = result.data else {
+        return Err(NetworkEconomicsError::ResponseCodeIsNotSuccessful { url, code: result.code }.into());
+    };
+
+    Ok(data.metrics)
+}
+
+async fn generate_blocks_until_epoch_reached(server_port: u16, epoch: u64) -> Result<(), LibError> {
+    loop {
+        let status = get_network_status(server_port, ShardId::Metachain).await?;
+
+        if status.epoch_number >= epoch {
+            return Ok(());
+        }
+
+        // The epoch changes on the block following the last round of the current one.
+        let remaining_rounds = status.rounds_per_epoch.saturating_sub(status.rounds_passed_in_current_epoch) + 1;
+        generate_blocks_and_check_progress(server_port, remaining_rounds, status.current_round).await?;
+    }
+}
+
+async fn generate_until_round(server_port: u16, round: u64) -> Result<(), LibError> {
+    loop {
+        let status = get_network_status(server_port, ShardId::Metachain).await?;
+
+        if status.current_round >= round {
+            return Ok(());
+        }
+
+        generate_blocks_and_check_progress(server_port, round - status.current_round, status.current_round).await?;
+    }
+}
+
+// Prevents the loops above from spinning forever on a chain that doesn't move.
+async fn generate_blocks_and_check_progress(server_port: u16, num_blocks: u64, current_round: u64) -> Result<(), LibError> {
+    generate_blocks(server_port, num_blocks).await?;
+
+    let status = get_network_status(server_port, ShardId::Metachain).await?;
 
-    let mut opt_process_id_and_options = process_and_options_mutex.lock().await;
-    let _ = kill(Pid::from_raw(process_id as i32), Signal::SIGKILL);
-    *opt_process_id_and_options = None;
+    if status.current_round <= current_round {
+        return Err(SimulatorError::ChainIsNotProgressing { round: current_round }.into());
+    }
 
     Ok(())
 }
 
-async fn get_simulator_process_id_and_options(process_and_options_mutex: Arc<Mutex<Option<(u32, SimulatorOptions)>>>) -> Result<(u32, SimulatorOptions), LibError> {
-    let opt_process_and_options = process_and_options_mutex.lock().await;
+fn get_rounds_for_duration(duration: Duration, round_duration_in_milliseconds: u64) -> u64 {
+    let round_duration_in_milliseconds = round_duration_in_milliseconds.max(1) as u128;
+
+    duration.as_millis().div_ceil(round_duration_in_milliseconds) as u64
+}
+
+fn start_autogeneration(context: &SimulatorContext, interval: Duration, blocks_per_tick: u64) -> Result<(), LibError> {
+    let instance_mutex = context.instance.clone();
+
+    context.autogeneration.start(interval, blocks_per_tick, context.events.clone(), move |num_blocks| {
+        let instance_mutex = instance_mutex.clone();
+
+        async move {
+            // The port is read at each tick, so the autogeneration follows the simulator across restarts.
+            let (_, options) = get_simulator_process_id_and_options(instance_mutex).await.ok()?;
+
+            Some(generate_blocks(options.server_port, num_blocks).await)
+        }
+    })
+}
+
+async fn kill_simulator_process(instance_mutex: SimulatorInstanceMutex) -> Result<(), LibError> {
+    let mut opt_instance = instance_mutex.lock().await;
+
+    let Some(instance) = opt_instance.take() else {
+        return Err(SimulatorError::ProcessNotStarted.into());
+    };
+
+    instance.supervisor.mark_as_stopping();
+
+    // Once the process has been waited on, its pid may belong to another process.
+    if instance.supervisor.get_exit().is_some() {
+        return Ok(());
+    }
+
+    // Removing a container waits on the container runtime's CLI.
+    let backend = instance.backend.clone();
+    run_blocking(move || backend.kill(instance.process_id)).await
+}
+
+async fn get_simulator_process_id_and_options(instance_mutex: SimulatorInstanceMutex) -> Result<(u32, SimulatorOptions), LibError> {
+    let opt_instance = instance_mutex.lock().await;
 
-    let Some((process_id, options)) = opt_process_and_options.as_ref() else {
+    let Some(instance) = opt_instance.as_ref() else {
         return Err(SimulatorError::ProcessNotStarted.into());
     };
 
-    if !is_process_running(*process_id) {
-        return Err(SimulatorError::ProcessAlreadyFinished.into());
+    if let Some(exit) = instance.supervisor.get_exit() {
+        let error = SimulatorError::ProcessExited {
+            code: exit.code,
+            signal: exit.signal,
+            last_log_lines: exit.last_log_lines,
+        };
+
+        return Err(error.into());
     }
 
-    Ok((*process_id, *options))
+    Ok((instance.process_id, instance.options.clone()))
 }
 
-fn is_process_running(pid: u32) -> bool {
-    match kill(Pid::from_raw(pid as i32), None) {
-        Ok(_) => true,
-        Err(Error::ESRCH) => false, // No such process
-        Err(_) => true, // The process exists but we don't have permission to send the signal
+// The servers receiving the nodes' data listen on the host's loopback, which a container can't reach.
+fn check_backend_supports_options(options: &SimulatorOptions) -> Result<(), LibError> {
+    if !matches!(options.backend, SimulatorBackendKind::Container(_)) {
+        return Ok(());
     }
-}
\ No newline at end of file
+
+    if options.outport_stream {
+        return Err(SimulatorError::OptionNotSupportedByContainerBackend { option: "outport_stream".to_string() }.into());
+    }
+
+    if options.event_notifier {
+        return Err(SimulatorError::OptionNotSupportedByContainerBackend { option: "event_notifier".to_string() }.into());
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use crate::error::lib::LibError;
+    use crate::error::simulator::SimulatorError;
+    use crate::simulator::model::{check_backend_supports_options, get_rounds_for_duration};
+    use crate::{ContainerOptions, SimulatorOptions};
+
+    #[test]
+    fn test_get_rounds_for_duration_rounds_up() {
+        assert_eq!(get_rounds_for_duration(Duration::ZERO, 6000), 0);
+        assert_eq!(get_rounds_for_duration(Duration::from_secs(6), 6000), 1);
+        assert_eq!(get_rounds_for_duration(Duration::from_millis(6001), 6000), 2);
+        assert_eq!(get_rounds_for_duration(Duration::from_secs(86400), 6000), 14400);
+    }
+
+    #[test]
+    fn test_container_backend_rejects_the_local_servers() {
+        let container_options = SimulatorOptions::new().with_container_backend(ContainerOptions::new());
+
+        assert_eq!(check_backend_supports_options(&container_options), Ok(()));
+        assert_eq!(check_backend_supports_options(&SimulatorOptions::new().with_outport_stream()), Ok(()));
+        assert_eq!(
+            check_backend_supports_options(&container_options.clone().with_outport_stream()),
+            Err(LibError::Simulator(SimulatorError::OptionNotSupportedByContainerBackend { option: "outport_stream".to_string() }))
+        );
+        assert_eq!(
+            check_backend_supports_options(&container_options.with_event_notifier()),
+            Err(LibError::Simulator(SimulatorError::OptionNotSupportedByContainerBackend { option: "event_notifier".to_string() }))
+        );
+    }
+}
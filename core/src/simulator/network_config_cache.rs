@@ -0,0 +1,87 @@
+use std::future::Future;
+use std::sync::Arc;
+
+use tokio::sync::Mutex;
+
+use crate::error::lib::LibError;
+use crate::simulator::requests::network_config::NetworkConfig;
+
+// The network config doesn't change while an instance runs, it is loaded again once the simulator restarts.
+#[derive(Clone, Default)]
+pub(crate) struct NetworkConfigCache {
+    config: Arc<Mutex<Option<NetworkConfig>>>,
+}
+
+impl NetworkConfigCache {
+    // The lock is held while loading, so concurrent transactions don't load it twice.
+    pub(crate) async fn get<F, Fut>(&self, load_config: F) -> Result<NetworkConfig, LibError>
+    where
+        F: FnOnce() -> Fut,
+        Fut: Future<Output = Result<NetworkConfig, LibError>>,
+    {
+        let mut config = self.config.lock().await;
+
+        if let Some(config) = config.as_ref() {
+            return Ok(config.clone());
+        }
+
+        let loaded_config = load_config().await?;
+        *config = Some(loaded_config.clone());
+
+        Ok(loaded_config)
+    }
+
+    pub(crate) async fn invalidate(&self) {
+        *self.config.lock().await = None;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::error::lib::LibError;
+    use crate::error::requests::network_config::NetworkConfigError;
+    use crate::simulator::network_config_cache::NetworkConfigCache;
+    use crate::simulator::requests::network_config::NetworkConfig;
+
+    fn get_config(chain_id: &str) -> NetworkConfig {
+        NetworkConfig {
+            chain_id: chain_id.to_string(),
+            denomination: 18,
+            gas_per_data_byte: 1500,
+            gas_price_modifier: "0.01".to_string(),
+            min_gas_limit: 50000,
+            min_gas_price: 1000000000,
+            min_transaction_version: 1,
+            extra_gas_limit_guarded_tx: 50000,
+            num_shards_without_meta: 3,
+            num_nodes_in_shard: 1,
+            num_metachain_nodes: 1,
+            shard_consensus_group_size: 1,
+            meta_consensus_group_size: 1,
+            round_duration: 6000,
+            rounds_per_epoch: 20,
+            start_time: 1700000000,
+        }
+    }
+
+    #[tokio::test]
+    async fn test_config_is_loaded_once_until_invalidated() {
+        let cache = NetworkConfigCache::default();
+
+        assert_eq!(cache.get(|| async { Ok(get_config("first")) }).await, Ok(get_config("first")));
+        assert_eq!(cache.get(|| async { Ok(get_config("second")) }).await, Ok(get_config("first")));
+
+        cache.invalidate().await;
+
+        assert_eq!(cache.get(|| async { Ok(get_config("second")) }).await, Ok(get_config("second")));
+    }
+
+    #[tokio::test]
+    async fn test_errors_are_not_cached() {
+        let cache = NetworkConfigCache::default();
+        let error = LibError::NetworkConfig(NetworkConfigError::CannotSendRequest { url: "url".to_string() });
+
+        assert_eq!(cache.get(|| async { Err(error.clone()) }).await, Err(error.clone()));
+        assert_eq!(cache.get(|| async { Ok(get_config("chain")) }).await, Ok(get_config("chain")));
+    }
+}
@@ -0,0 +1,283 @@
+use std::fs;
+use std::path::Path;
+
+use toml::{Table, Value};
+
+use crate::error::lib::LibError;
+use crate::error::node_config::NodeConfigError;
+use crate::simulator::api_routes::ApiRoutesConfig;
+use crate::utils::basic_auth::BasicAuthCredentials;
+
+pub(crate) const NODE_CONFIG_FOLDER: &str = "config/node/config";
+const MAIN_CONFIG_NAME: &str = "config.toml";
+const EXTERNAL_CONFIG_NAME: &str = "external.toml";
+const API_CONFIG_NAME: &str = "api.toml";
+
+#[derive(Clone, PartialEq, Debug, Default)]
+pub(crate) struct NodeConfigOverrides {
+    pub(crate) outport_url: Option<String>,
+    pub(crate) event_notifier_url: Option<String>,
+    pub(crate) event_notifier_credentials: Option<BasicAuthCredentials>,
+    pub(crate) elasticsearch: Option<ElasticsearchConnectorOverrides>,
+    pub(crate) api_routes: ApiRoutesConfig,
+}
+
+#[derive(Clone, PartialEq, Debug, Default)]
+pub(crate) struct ElasticsearchConnectorOverrides {
+    pub(crate) url: String,
+    pub(crate) indexes: Vec<String>,
+    pub(crate) credentials: Option<BasicAuthCredentials>,
+    pub(crate) bulk_request_max_size_in_bytes: Option<u64>,
+}
+
+pub(crate) fn apply_node_config_overrides(working_dir: &Path, overrides: &NodeConfigOverrides) -> Result<(), LibError> {
+    if let Some(outport_url) = &overrides.outport_url {
+        update_node_config_file(working_dir, EXTERNAL_CONFIG_NAME, |config, file_path| {
+            let host_driver = get_first_host_driver(config, file_path)?;
+
+            host_driver.insert("Enabled".to_string(), Value::Boolean(true));
+            host_driver.insert("Mode".to_string(), Value::String("client".to_string()));
+            host_driver.insert("URL".to_string(), Value::String(outport_url.clone()));
+            host_driver.insert("MarshallerType".to_string(), Value::String("json".to_string()));
+
+            Ok(())
+        })?;
+    }
+
+    if let Some(event_notifier_url) = &overrides.event_notifier_url {
+        update_node_config_file(working_dir, EXTERNAL_CONFIG_NAME, |config, file_path| {
+            let connector = get_section(config, file_path, "EventNotifierConnector")?;
+            let credentials = overrides.event_notifier_credentials.as_ref();
+
+            connector.insert("Enabled".to_string(), Value::Boolean(true));
+            connector.insert("ProxyUrl".to_string(), Value::String(event_notifier_url.clone()));
+            connector.insert("MarshallerType".to_string(), Value::String("json".to_string()));
+            connector.insert("UseAuthorization".to_string(), Value::Boolean(credentials.is_some()));
+            connector.insert("Username".to_string(), Value::String(credentials.map(|credentials| credentials.username.clone()).unwrap_or_default()));
+            connector.insert("Password".to_string(), Value::String(credentials.map(|credentials| credentials.password.clone()).unwrap_or_default()));
+
+            Ok(())
+        })?;
+    }
+
+    if let Some(elasticsearch) = &overrides.elasticsearch {
+        update_node_config_file(working_dir, EXTERNAL_CONFIG_NAME, |config, file_path| {
+            let connector = get_section(config, file_path, "ElasticSearchConnector")?;
+            let credentials = elasticsearch.credentials.as_ref();
+            let indexes = elasticsearch.indexes.iter().cloned().map(Value::String).collect();
+
+            connector.insert("Enabled".to_string(), Value::Boolean(true));
+            connector.insert("URL".to_string(), Value::String(elasticsearch.url.clone()));
+            connector.insert("EnabledIndexes".to_string(), Value::Array(indexes));
+            connector.insert("Username".to_string(), Value::String(credentials.map(|credentials| credentials.username.clone()).unwrap_or_default()));
+            connector.insert("Password".to_string(), Value::String(credentials.map(|credentials| credentials.password.clone()).unwrap_or_default()));
+
+            if let Some(bulk_request_max_size_in_bytes) = elasticsearch.bulk_request_max_size_in_bytes {
+                connector.insert("BulkRequestMaxSizeInBytes".to_string(), Value::Integer(bulk_request_max_size_in_bytes as i64));
+            }
+
+            Ok(())
+        })?;
+    }
+
+    if !overrides.api_routes.is_empty() {
+        update_node_config_file(working_dir, API_CONFIG_NAME, |config, file_path| overrides.api_routes.apply(config, file_path))?;
+    }
+
+    Ok(())
+}
+
+pub(crate) fn read_tx_pool_size_per_sender(working_dir: &Path) -> Option<usize> {
+    let config = read_node_config_file(working_dir, MAIN_CONFIG_NAME).ok()?;
+
+    let size_per_sender = config.get("TxDataPool")?.get("SizePerSender")?.as_integer()?;
+
+    usize::try_from(size_per_sender).ok()
+}
+
+pub(crate) fn get_section<'a>(config: &'a mut Table, file_path: &str, section: &str) -> Result<&'a mut Table, LibError> {
+    let Some(table) = config.get_mut(section).and_then(|table| table.as_table_mut()) else {
+        return Err(NodeConfigError::CannotFindSection { file_path: file_path.to_string(), section: section.to_string() }.into());
+    };
+
+    Ok(table)
+}
+
+fn get_first_host_driver<'a>(config: &'a mut Table, file_path: &str) -> Result<&'a mut Table, LibError> {
+    let host_driver = config
+        .get_mut("HostDriversConfig")
+        .and_then(|host_drivers| host_drivers.as_array_mut())
+        .and_then(|host_drivers| host_drivers.first_mut())
+        .and_then(|host_driver| host_driver.as_table_mut());
+
+    let Some(host_driver) = host_driver else {
+        return Err(NodeConfigError::CannotFindSection { file_path: file_path.to_string(), section: "HostDriversConfig".to_string() }.into());
+    };
+
+    Ok(host_driver)
+}
+
+fn update_node_config_file<F>(working_dir: &Path, file_name: &str, update: F) -> Result<(), LibError>
+where
+    F: FnOnce(&mut Table, &str) -> Result<(), LibError>,
+{
+    update_config_file(&working_dir.join(NODE_CONFIG_FOLDER).join(file_name), update)
+}
+
+fn read_node_config_file(working_dir: &Path, file_name: &str) -> Result<Table, LibError> {
+    read_config_file(&working_dir.join(NODE_CONFIG_FOLDER).join(file_name))
+}
+
+// The file's comments are not kept, it is a copy only read by the simulator.
+pub(crate) fn update_config_file<F>(file_path: &Path, update: F) -> Result<(), LibError>
+where
+    F: FnOnce(&mut Table, &str) -> Result<(), LibError>,
+{
+    let mut config = read_config_file(file_path)?;
+    let file_path_string = file_path.to_string_lossy().to_string();
+
+    update(&mut config, &file_path_string)?;
+
+    let Ok(()) = fs::write(file_path, config.to_string()) else {
+        return Err(NodeConfigError::CannotWriteFile { file_path: file_path_string }.into());
+    };
+
+    Ok(())
+}
+
+pub(crate) fn read_config_file(file_path: &Path) -> Result<Table, LibError> {
+    parse_config_file(file_path, fs::read_to_string(file_path).ok())
+}
+
+pub(crate) async fn read_config_file_async(file_path: &Path) -> Result<Table, LibError> {
+    parse_config_file(file_path, tokio::fs::read_to_string(file_path).await.ok())
+}
+
+fn parse_config_file(file_path: &Path, content: Option<String>) -> Result<Table, LibError> {
+    let file_path_string = file_path.to_string_lossy().to_string();
+
+    let Some(content) = content else {
+        return Err(NodeConfigError::CannotReadFile { file_path: file_path_string }.into());
+    };
+
+    let Ok(config) = content.parse::<Table>() else {
+        return Err(NodeConfigError::CannotParseFile { file_path: file_path_string }.into());
+    };
+
+    Ok(config)
+}
+
+// Copies a bundled config folder, such as `NODE_CONFIG_FOLDER`, in a new working directory.
+#[cfg(test)]
+pub(crate) fn copy_bundled_config(config_folder: &str) -> tempfile::TempDir {
+    let working_dir = tempfile::tempdir().unwrap();
+    let bundled_config = Path::new(env!("CARGO_MANIFEST_DIR")).join("../config/assets").join(config_folder);
+
+    crate::utils::fs::copy_dir_recursive(&bundled_config, &working_dir.path().join(config_folder)).unwrap();
+
+    working_dir
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::simulator::api_routes::ApiRoutesConfig;
+    use crate::simulator::node_config::{apply_node_config_overrides, ElasticsearchConnectorOverrides, copy_bundled_config, NODE_CONFIG_FOLDER, NodeConfigOverrides, read_node_config_file, read_tx_pool_size_per_sender};
+    use crate::utils::basic_auth::BasicAuthCredentials;
+
+    #[test]
+    fn test_read_tx_pool_size_per_sender_from_the_bundled_config() {
+        let working_dir = copy_bundled_config(NODE_CONFIG_FOLDER);
+
+        assert_eq!(read_tx_pool_size_per_sender(working_dir.path()), Some(20000));
+    }
+
+    #[test]
+    fn test_outport_url_enables_the_host_driver() {
+        let working_dir = copy_bundled_config(NODE_CONFIG_FOLDER);
+        let overrides = NodeConfigOverrides { outport_url: Some("127.0.0.1:4242".to_string()), ..Default::default() };
+
+        apply_node_config_overrides(working_dir.path(), &overrides).unwrap();
+
+        let config = read_node_config_file(working_dir.path(), "external.toml").unwrap();
+        let host_driver = &config["HostDriversConfig"][0];
+
+        assert_eq!(host_driver["Enabled"].as_bool(), Some(true));
+        assert_eq!(host_driver["URL"].as_str(), Some("127.0.0.1:4242"));
+        assert_eq!(host_driver["WithAcknowledge"].as_bool(), Some(true));
+        assert_eq!(config["EventNotifierConnector"]["Enabled"].as_bool(), Some(false));
+    }
+
+    #[test]
+    fn test_event_notifier_url_enables_the_connector() {
+        let working_dir = copy_bundled_config(NODE_CONFIG_FOLDER);
+        let overrides = NodeConfigOverrides {
+            event_notifier_url: Some("http://127.0.0.1:4343".to_string()),
+            event_notifier_credentials: Some(BasicAuthCredentials::new("notifier", "secret")),
+            ..Default::default()
+        };
+
+        apply_node_config_overrides(working_dir.path(), &overrides).unwrap();
+
+        let config = read_node_config_file(working_dir.path(), "external.toml").unwrap();
+        let connector = &config["EventNotifierConnector"];
+
+        assert_eq!(connector["Enabled"].as_bool(), Some(true));
+        assert_eq!(connector["ProxyUrl"].as_str(), Some("http://127.0.0.1:4343"));
+        assert_eq!(connector["UseAuthorization"].as_bool(), Some(true));
+        assert_eq!(connector["Username"].as_str(), Some("notifier"));
+        assert_eq!(connector["RequestTimeoutSec"].as_integer(), Some(60));
+        assert_eq!(config["HostDriversConfig"][0]["Enabled"].as_bool(), Some(false));
+    }
+
+    #[test]
+    fn test_elasticsearch_enables_the_connector_with_the_given_indexes() {
+        let working_dir = copy_bundled_config(NODE_CONFIG_FOLDER);
+        let overrides = NodeConfigOverrides {
+            elasticsearch: Some(ElasticsearchConnectorOverrides {
+                url: "http://127.0.0.1:9201".to_string(),
+                indexes: vec!["blocks".to_string(), "logs".to_string()],
+                credentials: None,
+                bulk_request_max_size_in_bytes: Some(1024),
+            }),
+            ..Default::default()
+        };
+
+        apply_node_config_overrides(working_dir.path(), &overrides).unwrap();
+
+        let config = read_node_config_file(working_dir.path(), "external.toml").unwrap();
+        let connector = &config["ElasticSearchConnector"];
+        let indexes: Vec<&str> = connector["EnabledIndexes"].as_array().unwrap().iter().map(|index| index.as_str().unwrap()).collect();
+
+        assert_eq!(connector["Enabled"].as_bool(), Some(true));
+        assert_eq!(connector["URL"].as_str(), Some("http://127.0.0.1:9201"));
+        assert_eq!(indexes, vec!["blocks", "logs"]);
+        assert_eq!(connector["BulkRequestMaxSizeInBytes"].as_integer(), Some(1024));
+        assert_eq!(connector["Username"].as_str(), Some(""));
+    }
+
+    #[test]
+    fn test_api_routes_are_written_to_the_api_config() {
+        let working_dir = copy_bundled_config(NODE_CONFIG_FOLDER);
+        let overrides = NodeConfigOverrides {
+            api_routes: ApiRoutesConfig::new().close_package("validator").close_route("node", "/debug"),
+            ..Default::default()
+        };
+
+        apply_node_config_overrides(working_dir.path(), &overrides).unwrap();
+
+        let config = read_node_config_file(working_dir.path(), "api.toml").unwrap();
+        let packages = &config["APIPackages"];
+        let is_open = |package: &str, route: &str| {
+            packages[package]["Routes"]
+                .as_array()
+                .unwrap()
+                .iter()
+                .find(|entry| entry["Name"].as_str() == Some(route))
+                .and_then(|entry| entry["Open"].as_bool())
+        };
+
+        assert_eq!(is_open("validator", "/statistics"), Some(false));
+        assert_eq!(is_open("node", "/debug"), Some(false));
+        assert_eq!(is_open("node", "/status"), Some(true));
+    }
+}
@@ -0,0 +1,102 @@
+use crate::error::lib::LibError;
+use crate::error::nodes_setup::NodesSetupError;
+use crate::simulator::shard::ShardId;
+
+// Values left unset keep the simulator's defaults.
+#[derive(Clone, PartialEq, Debug, Default)]
+pub(crate) struct NodesSetupOptions {
+    pub(crate) validators_per_shard: Option<u32>,
+    pub(crate) min_nodes_per_shard: Option<u32>,
+    pub(crate) consensus_group_size: Option<u32>,
+    pub(crate) metachain_validators: Option<u32>,
+    pub(crate) metachain_min_nodes: Option<u32>,
+    pub(crate) metachain_consensus_group_size: Option<u32>,
+}
+
+impl NodesSetupOptions {
+    pub(crate) fn validate(&self, num_of_shards: u64) -> Result<(), LibError> {
+        if num_of_shards == 0 {
+            return Err(NodesSetupError::NoShards.into());
+        }
+
+        validate_shard(ShardId::Shard(0), self.validators_per_shard, self.min_nodes_per_shard, self.consensus_group_size)?;
+        validate_shard(ShardId::Metachain, self.metachain_validators, self.metachain_min_nodes, self.metachain_consensus_group_size)
+    }
+
+    pub(crate) fn to_cli_args(&self) -> Vec<String> {
+        [
+            ("--num-validators-per-shard", self.validators_per_shard),
+            ("--min-nodes-per-shard", self.min_nodes_per_shard),
+            ("--consensus-group-size", self.consensus_group_size),
+            ("--num-validators-meta", self.metachain_validators),
+            ("--meta-chain-min-nodes", self.metachain_min_nodes),
+            ("--meta-chain-consensus-group-size", self.metachain_consensus_group_size),
+        ]
+            .into_iter()
+            .filter_map(|(flag, value)| Some([flag.to_string(), value?.to_string()]))
+            .flatten()
+            .collect()
+    }
+}
+
+// Each shard needs at least its min nodes to start, and a consensus group can't be larger than the min nodes.
+fn validate_shard(shard: ShardId, num_of_validators: Option<u32>, min_nodes: Option<u32>, consensus_group_size: Option<u32>) -> Result<(), LibError> {
+    if num_of_validators == Some(0) || min_nodes == Some(0) || consensus_group_size == Some(0) {
+        return Err(NodesSetupError::ZeroNodes { shard }.into());
+    }
+
+    if let (Some(min_nodes), Some(num_of_validators)) = (min_nodes, num_of_validators) {
+        if min_nodes > num_of_validators {
+            return Err(NodesSetupError::NotEnoughValidators { shard, min_nodes, num_of_validators }.into());
+        }
+    }
+
+    let max_consensus_group_size = min_nodes.or(num_of_validators);
+    if let (Some(consensus_group_size), Some(max_consensus_group_size)) = (consensus_group_size, max_consensus_group_size) {
+        if consensus_group_size > max_consensus_group_size {
+            return Err(NodesSetupError::InvalidConsensusGroupSize { shard, consensus_group_size, max_consensus_group_size }.into());
+        }
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::error::lib::LibError;
+    use crate::error::nodes_setup::NodesSetupError;
+    use crate::simulator::nodes_setup::NodesSetupOptions;
+    use crate::simulator::shard::ShardId;
+
+    #[test]
+    fn test_only_the_set_values_are_passed() {
+        let options = NodesSetupOptions {
+            validators_per_shard: Some(4),
+            consensus_group_size: Some(2),
+            metachain_min_nodes: Some(1),
+            ..Default::default()
+        };
+
+        assert_eq!(options.validate(3), Ok(()));
+        assert_eq!(
+            options.to_cli_args(),
+            vec!["--num-validators-per-shard", "4", "--consensus-group-size", "2", "--meta-chain-min-nodes", "1"]
+        );
+    }
+
+    #[test]
+    fn test_inconsistent_values_are_errors() {
+        let consensus_too_large = NodesSetupOptions { metachain_min_nodes: Some(2), metachain_consensus_group_size: Some(3), ..Default::default() };
+        let not_enough_validators = NodesSetupOptions { validators_per_shard: Some(1), min_nodes_per_shard: Some(2), ..Default::default() };
+
+        assert_eq!(
+            consensus_too_large.validate(3),
+            Err(LibError::NodesSetup(NodesSetupError::InvalidConsensusGroupSize { shard: ShardId::Metachain, consensus_group_size: 3, max_consensus_group_size: 2 }))
+        );
+        assert_eq!(
+            not_enough_validators.validate(3),
+            Err(LibError::NodesSetup(NodesSetupError::NotEnoughValidators { shard: ShardId::Shard(0), min_nodes: 2, num_of_validators: 1 }))
+        );
+        assert_eq!(NodesSetupOptions::default().validate(0), Err(LibError::NodesSetup(NodesSetupError::NoShards)));
+    }
+}
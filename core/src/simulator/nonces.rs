@@ -0,0 +1,102 @@
+use std::collections::HashMap;
+use std::future::Future;
+use std::sync::Arc;
+
+use tokio::sync::Mutex;
+
+use crate::error::lib::LibError;
+
+#[derive(Clone, Default)]
+pub(crate) struct NonceManager {
+    next_nonces: Arc<Mutex<HashMap<String, u64>>>,
+}
+
+impl NonceManager {
+    // The lock is held while loading, so concurrent sends from a new sender don't load it twice.
+    pub(crate) async fn next_nonce<F, Fut>(&self, address: &str, load_nonce: F) -> Result<u64, LibError>
+    where
+        F: FnOnce() -> Fut,
+        Fut: Future<Output = Result<u64, LibError>>,
+    {
+        let mut next_nonces = self.next_nonces.lock().await;
+
+        let next_nonce = match next_nonces.get(address) {
+            Some(next_nonce) => *next_nonce,
+            None => load_nonce().await?,
+        };
+
+        next_nonces.insert(address.to_string(), next_nonce + 1);
+
+        Ok(next_nonce)
+    }
+
+    pub(crate) async fn peek_nonce<F, Fut>(&self, address: &str, load_nonce: F) -> Result<u64, LibError>
+    where
+        F: FnOnce() -> Fut,
+        Fut: Future<Output = Result<u64, LibError>>,
+    {
+        let next_nonces = self.next_nonces.lock().await;
+
+        match next_nonces.get(address) {
+            Some(next_nonce) => Ok(*next_nonce),
+            None => load_nonce().await,
+        }
+    }
+
+    pub(crate) async fn invalidate(&self, address: &str) {
+        self.next_nonces.lock().await.remove(address);
+    }
+
+    pub(crate) async fn invalidate_all(&self) {
+        self.next_nonces.lock().await.clear();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+    use std::sync::atomic::{AtomicU64, Ordering};
+
+    use crate::simulator::nonces::NonceManager;
+
+    #[tokio::test]
+    async fn test_concurrent_nonces_are_sequential_and_loaded_once() {
+        let nonces = NonceManager::default();
+        let loads = Arc::new(AtomicU64::new(0));
+
+        let tasks: Vec<_> = (0..20)
+            .map(|_| {
+                let nonces = nonces.clone();
+                let loads = loads.clone();
+
+                tokio::spawn(async move {
+                    nonces.next_nonce("erd1sender", || async move {
+                        loads.fetch_add(1, Ordering::SeqCst);
+                        Ok(7)
+                    }).await.unwrap()
+                })
+            })
+            .collect();
+
+        let mut handed_out = vec![];
+        for task in tasks {
+            handed_out.push(task.await.unwrap());
+        }
+        handed_out.sort();
+
+        assert_eq!(handed_out, (7..27).collect::<Vec<u64>>());
+        assert_eq!(loads.load(Ordering::SeqCst), 1);
+    }
+
+    #[tokio::test]
+    async fn test_invalidate_reloads_the_nonce() {
+        let nonces = NonceManager::default();
+
+        assert_eq!(nonces.next_nonce("erd1sender", || async { Ok(3) }).await, Ok(3));
+        assert_eq!(nonces.peek_nonce("erd1sender", || async { Ok(0) }).await, Ok(4));
+
+        nonces.invalidate("erd1sender").await;
+
+        assert_eq!(nonces.next_nonce("erd1sender", || async { Ok(10) }).await, Ok(10));
+    }
+}
@@ -0,0 +1,61 @@
+use serde::Deserialize;
+
+use crate::simulator::outport::model::OutportBlock;
+
+pub(crate) const PUSH_EVENTS_PATH: &str = "/events/push";
+pub(crate) const REVERT_EVENTS_PATH: &str = "/events/revert";
+pub(crate) const FINALIZED_EVENTS_PATH: &str = "/events/finalized";
+
+#[derive(Clone, PartialEq, Debug)]
+pub(crate) enum NotifierEvent {
+    // Events are in the transaction pool's logs of the pushed block.
+    Push(OutportBlock),
+    Revert(NotifierRevertedBlock),
+    Finalized(NotifierFinalizedBlock),
+}
+
+impl NotifierEvent {
+    pub(crate) fn decode(path: &str, body: &[u8]) -> Option<Result<NotifierEvent, serde_json::Error>> {
+        let event = match path {
+            PUSH_EVENTS_PATH => serde_json::from_slice(body).map(NotifierEvent::Push),
+            REVERT_EVENTS_PATH => serde_json::from_slice(body).map(NotifierEvent::Revert),
+            FINALIZED_EVENTS_PATH => serde_json::from_slice(body).map(NotifierEvent::Finalized),
+            _ => return None,
+        };
+
+        Some(event)
+    }
+}
+
+// Hashes are hex encoded by the notifier connector, unlike the outport data.
+#[derive(Deserialize, Clone, PartialEq, Debug, Default)]
+#[serde(default)]
+pub struct NotifierRevertedBlock {
+    pub hash: String,
+    pub nonce: u64,
+    pub round: u64,
+    pub epoch: u32,
+}
+
+#[derive(Deserialize, Clone, PartialEq, Debug, Default)]
+#[serde(default)]
+pub struct NotifierFinalizedBlock {
+    pub hash: String,
+}
+
+#[derive(Clone, PartialEq, Debug, Default)]
+pub struct NotifierEvents {
+    pub pushed: Vec<OutportBlock>,
+    pub reverted: Vec<NotifierRevertedBlock>,
+    pub finalized: Vec<NotifierFinalizedBlock>,
+}
+
+impl NotifierEvents {
+    pub(crate) fn push(&mut self, event: NotifierEvent) {
+        match event {
+            NotifierEvent::Push(block) => self.pushed.push(block),
+            NotifierEvent::Revert(block) => self.reverted.push(block),
+            NotifierEvent::Finalized(block) => self.finalized.push(block),
+        }
+    }
+}
@@ -0,0 +1,158 @@
+use std::convert::Infallible;
+use std::net::SocketAddr;
+use std::sync::{Arc, Mutex};
+
+use hyper::{Body, Request, Response, StatusCode};
+use hyper::header::AUTHORIZATION;
+use tokio::task::JoinHandle;
+
+use crate::error::lib::LibError;
+use crate::error::simulator::SimulatorError;
+use crate::simulator::notifier::model::{NotifierEvent, NotifierEvents};
+use crate::utils::basic_auth::BasicAuthCredentials;
+use crate::utils::local_server::spawn_local_http_server;
+
+struct RunningNotifierReceiver {
+    address: SocketAddr,
+    task: JoinHandle<()>,
+}
+
+#[derive(Clone, Default)]
+pub(crate) struct NotifierReceiver {
+    running: Arc<Mutex<Option<RunningNotifierReceiver>>>,
+    authorization: Arc<Mutex<Option<String>>>,
+    events: Arc<Mutex<NotifierEvents>>,
+}
+
+impl NotifierReceiver {
+    // The credentials are replaced on each call, as they may change between restarts.
+    pub(crate) fn start(&self, credentials: Option<&BasicAuthCredentials>) -> Result<String, LibError> {
+        *self.authorization.lock().unwrap() = credentials.map(|credentials| credentials.authorization_header());
+
+        let mut running = self.running.lock().unwrap();
+
+        if let Some(running) = running.as_ref() {
+            return Ok(format!("http://{}", running.address));
+        }
+
+        let authorization = self.authorization.clone();
+        let events = self.events.clone();
+        let handle = move |request| handle_request(request, authorization.clone(), events.clone());
+
+        let Some((address, task)) = spawn_local_http_server(handle) else {
+            return Err(SimulatorError::CannotStartEventNotifierReceiver.into());
+        };
+
+        *running = Some(RunningNotifierReceiver { address, task });
+
+        Ok(format!("http://{address}"))
+    }
+
+    pub(crate) fn is_running(&self) -> bool {
+        self.running.lock().unwrap().is_some()
+    }
+
+    pub(crate) fn get_events(&self) -> NotifierEvents {
+        self.events.lock().unwrap().clone()
+    }
+
+    pub(crate) fn clear_events(&self) {
+        *self.events.lock().unwrap() = NotifierEvents::default();
+    }
+
+    pub(crate) fn stop(&self) {
+        if let Some(running) = self.running.lock().unwrap().take() {
+            running.task.abort();
+        }
+    }
+}
+
+async fn handle_request(
+    request: Request<Body>,
+    authorization: Arc<Mutex<Option<String>>>,
+    events: Arc<Mutex<NotifierEvents>>,
+) -> Result<Response<Body>, Infallible> {
+    let expected_authorization = authorization.lock().unwrap().clone();
+    if let Some(expected_authorization) = expected_authorization {
+        let received_authorization = request.headers()
+            .get(AUTHORIZATION)
+            .and_then(|header| header.to_str().ok());
+
+        if received_authorization != Some(expected_authorization.as_str()) {
+            return Ok(empty_response(StatusCode::UNAUTHORIZED));
+        }
+    }
+
+    let path = request.uri().path().to_string();
+    let Ok(body) = hyper::body::to_bytes(request.into_body()).await else {
+        return Ok(empty_response(StatusCode::BAD_REQUEST));
+    };
+
+    // Payloads that cannot be decoded are accepted anyway, otherwise the node would retry them forever and stop producing blocks.
+    match NotifierEvent::decode(&path, &body) {
+        Some(Ok(event)) => events.lock().unwrap().push(event),
+        Some(Err(_)) => {},
+        None => return Ok(empty_response(StatusCode::NOT_FOUND)),
+    }
+
+    Ok(empty_response(StatusCode::OK))
+}
+
+fn empty_response(status: StatusCode) -> Response<Body> {
+    let mut response = Response::new(Body::empty());
+    *response.status_mut() = status;
+
+    response
+}
+
+#[cfg(test)]
+mod tests {
+    use reqwest::{Client, StatusCode};
+
+    use crate::simulator::notifier::model::{NotifierFinalizedBlock, NotifierRevertedBlock};
+    use crate::simulator::notifier::server::NotifierReceiver;
+    use crate::utils::basic_auth::BasicAuthCredentials;
+
+    #[tokio::test]
+    async fn test_payloads_are_captured_with_basic_auth() {
+        let receiver = NotifierReceiver::default();
+        let credentials = BasicAuthCredentials::new("notifier", "secret");
+        let url = receiver.start(Some(&credentials)).unwrap();
+
+        assert_eq!(receiver.start(Some(&credentials)).unwrap(), url);
+
+        let client = Client::new();
+
+        let unauthorized = client.post(format!("{url}/events/finalized"))
+            .body(r#"{"hash":"aa"}"#)
+            .send()
+            .await
+            .unwrap();
+
+        assert_eq!(unauthorized.status(), StatusCode::UNAUTHORIZED);
+
+        for (path, body) in [("finalized", r#"{"hash":"aa"}"#), ("revert", r#"{"hash":"bb","nonce":5,"round":6,"epoch":1}"#), ("push", r#"{"shardID":2}"#)] {
+            let response = client.post(format!("{url}/events/{path}"))
+                .basic_auth("notifier", Some("secret"))
+                .body(body)
+                .send()
+                .await
+                .unwrap();
+
+            assert_eq!(response.status(), StatusCode::OK);
+        }
+
+        let events = receiver.get_events();
+
+        assert_eq!(events.finalized, vec![NotifierFinalizedBlock { hash: "aa".to_string() }]);
+        assert_eq!(events.reverted, vec![NotifierRevertedBlock { hash: "bb".to_string(), nonce: 5, round: 6, epoch: 1 }]);
+        assert_eq!(events.pushed.len(), 1);
+        assert_eq!(events.pushed[0].shard_id, 2);
+
+        receiver.clear_events();
+        assert!(receiver.get_events().pushed.is_empty());
+
+        receiver.stop();
+        assert!(!receiver.is_running());
+    }
+}
@@ -0,0 +1,147 @@
+use std::collections::{BTreeMap, HashSet};
+use std::fs;
+use std::time::Duration;
+
+use reqwest::Client;
+
+use crate::error::lib::LibError;
+use crate::error::simulator::SimulatorError;
+use crate::simulator::backend::SimulatorBackendKind;
+use crate::simulator::model::Simulator;
+use crate::simulator::requests::node_status::{NodeStatus, NodeStatusResponse};
+use crate::simulator::shard::ShardId;
+
+const NODE_STATUS_TIMEOUT: Duration = Duration::from_millis(500);
+const LISTEN_SOCKET_STATE: &str = "0A";
+
+impl Simulator {
+    // Discovered among the ports the simulator's process listens on.
+    pub async fn get_observer_endpoints(&self) -> Result<BTreeMap<ShardId, Vec<String>>, LibError> {
+        let (process_id, options) = self.get_process_id_and_options().await?;
+
+        // The process' sockets are read from /proc, a container's process id isn't one of the host.
+        if !cfg!(target_os = "linux") || matches!(options.backend, SimulatorBackendKind::Container(_)) {
+            return Err(SimulatorError::ObserversDiscoveryNotSupported.into());
+        }
+
+        let client = Client::builder()
+            .timeout(NODE_STATUS_TIMEOUT)
+            .build()
+            .unwrap_or_default();
+
+        let mut endpoints: BTreeMap<ShardId, Vec<String>> = BTreeMap::new();
+        for port in find_listening_ports(process_id) {
+            if port == options.server_port {
+                continue;
+            }
+
+            let url = format!("http://localhost:{port}");
+            if let Some(status) = get_node_status(&client, &url).await {
+                endpoints.entry(ShardId::from(status.shard_id)).or_default().push(url);
+            }
+        }
+
+        if endpoints.is_empty() {
+            return Err(SimulatorError::ObserversNotFound.into());
+        }
+
+        Ok(endpoints)
+    }
+
+    pub async fn get_observer_endpoint(&self, shard: ShardId) -> Result<String, LibError> {
+        let endpoints = self.get_observer_endpoints().await?;
+
+        let Some(endpoint) = endpoints.get(&shard).and_then(|endpoints| endpoints.first()) else {
+            return Err(SimulatorError::ObserversNotFound.into());
+        };
+
+        Ok(endpoint.clone())
+    }
+
+    pub async fn get_address_shard(&self, address: &str) -> Result<ShardId, LibError> {
+        let (_, options) = self.get_process_id_and_options().await?;
+
+        let Some(shard) = ShardId::of_address(address, options.num_of_shards as u32) else {
+            return Err(SimulatorError::InvalidAddress { address: address.to_string() }.into());
+        };
+
+        Ok(shard)
+    }
+}
+
+// Any port which doesn't answer like a node is ignored, such as the pprof or the outport ones.
+async fn get_node_status(client: &Client, url: &str) -> Option<NodeStatus> {
+    let response = client.get(format!("{url}/node/status")).send().await.ok()?;
+    let text = response.text().await.ok()?;
+    let response = serde_json::from_str::<NodeStatusResponse>(&text).ok()?;
+
+    if response.code != "successful" {
+        return None;
+    }
+
+    Some(response.data?.metrics)
+}
+
+fn find_listening_ports(process_id: u32) -> Vec<u16> {
+    let Ok(fds) = fs::read_dir(format!("/proc/{process_id}/fd")) else {
+        return vec![];
+    };
+
+    let socket_inodes: HashSet<String> = fds
+        .filter_map(|fd| fs::read_link(fd.ok()?.path()).ok())
+        .filter_map(|link| {
+            let inode = link.to_str()?.strip_prefix("socket:[")?.strip_suffix(']')?;
+
+            Some(inode.to_string())
+        })
+        .collect();
+
+    let mut ports = vec![];
+    for table_path in ["/proc/net/tcp", "/proc/net/tcp6"] {
+        if let Ok(table) = fs::read_to_string(table_path) {
+            ports.extend(parse_listening_ports(&table, &socket_inodes));
+        }
+    }
+
+    ports.sort();
+    ports.dedup();
+
+    ports
+}
+
+fn parse_listening_ports(table: &str, socket_inodes: &HashSet<String>) -> Vec<u16> {
+    table
+        .lines()
+        .skip(1)
+        .filter_map(|line| {
+            let columns: Vec<&str> = line.split_whitespace().collect();
+            let (local_address, state, inode) = (columns.get(1)?, columns.get(3)?, columns.get(9)?);
+
+            if *state != LISTEN_SOCKET_STATE || !socket_inodes.contains(*inode) {
+                return None;
+            }
+
+            u16::from_str_radix(local_address.rsplit(':').next()?, 16).ok()
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashSet;
+
+    use crate::simulator::observers::parse_listening_ports;
+
+    #[test]
+    fn test_parse_listening_ports() {
+        let table = "  sl  local_address rem_address   st tx_queue rx_queue tr tm->when retrnsmt   uid  timeout inode
+   0: 0100007F:1F95 00000000:0000 0A 00000000:00000000 00:00000000 00000000     0        0 1001 1 0000000000000000 100 0 0 10 0
+   1: 0100007F:9C41 00000000:0000 0A 00000000:00000000 00:00000000 00000000     0        0 1002 1 0000000000000000 100 0 0 10 0
+   2: 0100007F:9C42 0100007F:1F95 01 00000000:00000000 00:00000000 00000000     0        0 1003 1 0000000000000000 100 0 0 10 0
+   3: 0100007F:9C43 00000000:0000 0A 00000000:00000000 00:00000000 00000000     0        0 2000 1 0000000000000000 100 0 0 10 0";
+
+        let socket_inodes: HashSet<String> = ["1001", "1002", "1003"].into_iter().map(str::to_string).collect();
+
+        assert_eq!(parse_listening_ports(table, &socket_inodes), vec![8085, 40001]);
+    }
+}
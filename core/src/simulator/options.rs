@@ -1,13 +1,44 @@
+use std::path::PathBuf;
 use std::time::Duration;
+use crate::simulator::api_routes::ApiRoutesConfig;
+use crate::simulator::backend::container::ContainerOptions;
+use crate::simulator::backend::SimulatorBackendKind;
 use crate::simulator::config::SimulatorConfig;
+use crate::simulator::nodes_setup::NodesSetupOptions;
+use crate::utils::basic_auth::BasicAuthCredentials;
+use crate::simulator::proxy_config::ProxyOptions;
+use crate::simulator::readiness::ReadinessPolicy;
+use crate::simulator::requests::set_state::SetStateAddress;
 
-#[derive(Copy, Clone)]
+#[derive(Clone)]
 pub struct SimulatorOptions {
     pub(crate) server_port: u16,
     pub(crate) num_of_shards: u64,
     pub(crate) rounds_per_epoch: u64,
     pub(crate) bypass_txs_signature: bool,
-    pub(crate) block_autogenerate_duration: Option<Duration>
+    pub(crate) block_autogenerate_duration: Option<Duration>,
+    pub(crate) blocks_per_autogeneration_tick: u64,
+    pub(crate) log_level: String,
+    pub(crate) save_logs_to_file: bool,
+    pub(crate) logs_path: PathBuf,
+    pub(crate) log_file_prefix: String,
+    pub(crate) log_file_life_span_in_mb: u64,
+    pub(crate) log_file_life_span_in_sec: u64,
+    pub(crate) binary_path: Option<PathBuf>,
+    pub(crate) backend: SimulatorBackendKind,
+    pub(crate) readiness_policy: ReadinessPolicy,
+    pub(crate) max_restarts: u32,
+    pub(crate) initial_state: Vec<SetStateAddress>,
+    pub(crate) outport_stream: bool,
+    pub(crate) event_notifier: bool,
+    pub(crate) event_notifier_credentials: Option<BasicAuthCredentials>,
+    pub(crate) elasticsearch_url: Option<String>,
+    pub(crate) elasticsearch_indexes: Vec<String>,
+    pub(crate) elasticsearch_credentials: Option<BasicAuthCredentials>,
+    pub(crate) elasticsearch_bulk_request_max_size_in_bytes: Option<u64>,
+    pub(crate) proxy: ProxyOptions,
+    pub(crate) node_api_routes: ApiRoutesConfig,
+    pub(crate) nodes_setup: NodesSetupOptions,
 }
 
 impl SimulatorOptions {
@@ -45,20 +76,192 @@ impl SimulatorOptions {
         self
     }
 
-    pub fn to_cli_args(&self) -> Vec<String> {
-        let mut result = vec![];
+    pub fn with_blocks_per_autogeneration_tick(mut self, blocks_per_tick: u64) -> Self {
+        self.blocks_per_autogeneration_tick = blocks_per_tick;
+
+        self
+    }
+
+    // Either global (`*:DEBUG`) or per component (`*:INFO,process:DEBUG`).
+    pub fn with_log_level(mut self, log_level: impl Into<String>) -> Self {
+        self.log_level = log_level.into();
+
+        self
+    }
+
+    pub fn save_logs_to_file(mut self) -> Self {
+        self.save_logs_to_file = true;
+
+        self
+    }
+
+    // A relative path is inside the working directory, which is deleted with the `Simulator`.
+    pub fn with_logs_path(mut self, logs_path: impl Into<PathBuf>) -> Self {
+        self.logs_path = logs_path.into();
+
+        self
+    }
+
+    pub fn with_log_file_prefix(mut self, log_file_prefix: impl Into<String>) -> Self {
+        self.log_file_prefix = log_file_prefix.into();
+
+        self
+    }
+
+    pub fn with_log_file_life_span_in_mb(mut self, log_file_life_span_in_mb: u64) -> Self {
+        self.log_file_life_span_in_mb = log_file_life_span_in_mb;
+
+        self
+    }
+
+    pub fn with_log_file_life_span_in_sec(mut self, log_file_life_span_in_sec: u64) -> Self {
+        self.log_file_life_span_in_sec = log_file_life_span_in_sec;
+
+        self
+    }
+
+    pub fn with_binary_path(mut self, binary_path: impl Into<PathBuf>) -> Self {
+        self.binary_path = Some(binary_path.into());
+
+        self
+    }
+
+    pub fn with_container_backend(mut self, container_options: ContainerOptions) -> Self {
+        self.backend = SimulatorBackendKind::Container(container_options);
+
+        self
+    }
+
+    pub fn with_readiness_policy(mut self, readiness_policy: ReadinessPolicy) -> Self {
+        self.readiness_policy = readiness_policy;
+
+        self
+    }
+
+    pub fn with_restart_on_crash(mut self, max_restarts: u32) -> Self {
+        self.max_restarts = max_restarts;
+
+        self
+    }
+
+    // Applied again after each restart.
+    pub fn with_initial_state(mut self, initial_state: Vec<SetStateAddress>) -> Self {
+        self.initial_state = initial_state;
+
+        self
+    }
+
+    pub fn with_outport_stream(mut self) -> Self {
+        self.outport_stream = true;
+
+        self
+    }
+
+    pub fn with_event_notifier(mut self) -> Self {
+        self.event_notifier = true;
+
+        self
+    }
+
+    pub fn with_event_notifier_authorization(mut self, username: impl Into<String>, password: impl Into<String>) -> Self {
+        self.event_notifier = true;
+        self.event_notifier_credentials = Some(BasicAuthCredentials::new(username, password));
+
+        self
+    }
+
+    // The URL must be reachable when the simulator starts, it can be the one of a `FakeElasticsearch`.
+    pub fn with_elasticsearch<I, S>(mut self, url: impl Into<String>, indexes: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        self.elasticsearch_url = Some(url.into());
+        self.elasticsearch_indexes = indexes.into_iter().map(Into::into).collect();
 
-        result.push("--server-port".to_string());
-        result.push(self.server_port.to_string());
+        self
+    }
+
+    pub fn with_elasticsearch_credentials(mut self, username: impl Into<String>, password: impl Into<String>) -> Self {
+        self.elasticsearch_credentials = Some(BasicAuthCredentials::new(username, password));
+
+        self
+    }
+
+    pub fn with_elasticsearch_bulk_request_max_size(mut self, max_size_in_bytes: u64) -> Self {
+        self.elasticsearch_bulk_request_max_size_in_bytes = Some(max_size_in_bytes);
+
+        self
+    }
+
+    pub fn with_proxy_options(mut self, proxy: ProxyOptions) -> Self {
+        self.proxy = proxy;
+
+        self
+    }
 
-        result.push("--num-of-shards".to_string());
-        result.push(self.num_of_shards.to_string());
+    // The simulator's proxy relies on some of them, such as `/node/status` or `/address/:address`.
+    pub fn with_node_api_routes(mut self, node_api_routes: ApiRoutesConfig) -> Self {
+        self.node_api_routes = node_api_routes;
 
-        result.push("--rounds-per-epoch".to_string());
-        result.push(self.rounds_per_epoch.to_string());
+        self
+    }
 
-        result.push("--bypass-txs-signature".to_string());
-        result.push(self.bypass_txs_signature.to_string());
+    pub fn with_validators_per_shard(mut self, validators_per_shard: u32) -> Self {
+        self.nodes_setup.validators_per_shard = Some(validators_per_shard);
+
+        self
+    }
+
+    pub fn with_min_nodes_per_shard(mut self, min_nodes_per_shard: u32) -> Self {
+        self.nodes_setup.min_nodes_per_shard = Some(min_nodes_per_shard);
+
+        self
+    }
+
+    pub fn with_metachain_validators(mut self, metachain_validators: u32) -> Self {
+        self.nodes_setup.metachain_validators = Some(metachain_validators);
+
+        self
+    }
+
+    pub fn with_metachain_min_nodes(mut self, metachain_min_nodes: u32) -> Self {
+        self.nodes_setup.metachain_min_nodes = Some(metachain_min_nodes);
+
+        self
+    }
+
+    pub fn with_consensus_group_size(mut self, consensus_group_size: u32) -> Self {
+        self.nodes_setup.consensus_group_size = Some(consensus_group_size);
+
+        self
+    }
+
+    pub fn with_metachain_consensus_group_size(mut self, metachain_consensus_group_size: u32) -> Self {
+        self.nodes_setup.metachain_consensus_group_size = Some(metachain_consensus_group_size);
+
+        self
+    }
+
+    pub fn to_cli_args(&self) -> Vec<String> {
+        let mut result = vec![
+            "--server-port".to_string(),
+            self.server_port.to_string(),
+            "--num-of-shards".to_string(),
+            self.num_of_shards.to_string(),
+            "--rounds-per-epoch".to_string(),
+            self.rounds_per_epoch.to_string(),
+            "--bypass-txs-signature".to_string(),
+            self.bypass_txs_signature.to_string(),
+            "--log-level".to_string(),
+            self.log_level.clone(),
+        ];
+
+        if self.save_logs_to_file {
+            result.push("--log-save".to_string());
+        }
+
+        result.extend(self.nodes_setup.to_cli_args());
 
         result
     }
@@ -71,13 +274,89 @@ impl Default for SimulatorOptions {
             num_of_shards: 3,
             rounds_per_epoch: 20,
             bypass_txs_signature: false,
-            block_autogenerate_duration: None
+            block_autogenerate_duration: None,
+            blocks_per_autogeneration_tick: 1,
+            log_level: "*:INFO".to_string(),
+            save_logs_to_file: false,
+            logs_path: PathBuf::from("logs"),
+            log_file_prefix: "chain-simulator".to_string(),
+            log_file_life_span_in_mb: 1024,
+            log_file_life_span_in_sec: 432000,
+            binary_path: None,
+            backend: SimulatorBackendKind::NativeProcess,
+            readiness_policy: ReadinessPolicy::default(),
+            max_restarts: 0,
+            initial_state: vec![],
+            outport_stream: false,
+            event_notifier: false,
+            event_notifier_credentials: None,
+            elasticsearch_url: None,
+            elasticsearch_indexes: vec![],
+            elasticsearch_credentials: None,
+            elasticsearch_bulk_request_max_size_in_bytes: None,
+            proxy: ProxyOptions::default(),
+            node_api_routes: ApiRoutesConfig::default(),
+            nodes_setup: NodesSetupOptions::default(),
         }
     }
 }
 
-impl From<SimulatorOptions> for SimulatorConfig {
-    fn from(_value: SimulatorOptions) -> Self {
-        SimulatorConfig::default()
+impl From<&SimulatorOptions> for SimulatorConfig {
+    fn from(value: &SimulatorOptions) -> Self {
+        let mut config = SimulatorConfig::default();
+
+        let logs = &mut config.config.logs;
+        logs.log_file_life_span_in_mb = value.log_file_life_span_in_mb;
+        logs.log_file_life_span_in_sec = value.log_file_life_span_in_sec;
+        logs.log_file_prefix = value.log_file_prefix.clone();
+        logs.logs_path = value.logs_path.to_string_lossy().to_string();
+
+        config
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::simulator::config::SimulatorConfig;
+    use crate::SimulatorOptions;
+
+    #[test]
+    fn test_to_cli_args_default_log_level() {
+        let args = SimulatorOptions::new().to_cli_args();
+
+        let log_level_index = args.iter().position(|arg| arg == "--log-level").unwrap();
+
+        assert_eq!(args[log_level_index + 1], "*:INFO");
+        assert!(!args.contains(&"--log-save".to_string()));
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn test_to_cli_args_with_logs_saved_to_file() {
+        let args = SimulatorOptions::new()
+            .with_log_level("*:INFO,process:DEBUG")
+            .save_logs_to_file()
+            .to_cli_args();
+
+        let log_level_index = args.iter().position(|arg| arg == "--log-level").unwrap();
+
+        assert_eq!(args[log_level_index + 1], "*:INFO,process:DEBUG");
+        assert!(args.contains(&"--log-save".to_string()));
+    }
+
+    #[test]
+    fn test_config_from_options_logs() {
+        let options = SimulatorOptions::new()
+            .with_logs_path("/tmp/simulator-logs")
+            .with_log_file_prefix("ci")
+            .with_log_file_life_span_in_mb(10)
+            .with_log_file_life_span_in_sec(60);
+
+        let config = SimulatorConfig::from(&options);
+        let logs = &config.config.logs;
+
+        assert_eq!(logs.logs_path, "/tmp/simulator-logs");
+        assert_eq!(logs.log_file_prefix, "ci");
+        assert_eq!(logs.log_file_life_span_in_mb, 10);
+        assert_eq!(logs.log_file_life_span_in_sec, 60);
+    }
+}
@@ -0,0 +1,159 @@
+use std::collections::HashMap;
+
+use base64::Engine;
+use base64::engine::general_purpose::STANDARD as BASE64;
+use serde::Deserialize;
+
+pub(crate) const SAVE_BLOCK_TOPIC: &str = "SaveBlock";
+pub(crate) const REVERT_INDEXED_BLOCK_TOPIC: &str = "RevertIndexedBlock";
+pub(crate) const FINALIZED_BLOCK_TOPIC: &str = "FinalizedBlock";
+
+pub(crate) const PAYLOAD_MESSAGE_TYPE: i32 = 1;
+pub(crate) const ACK_MESSAGE_TYPE: i32 = 2;
+
+#[derive(serde::Serialize, Deserialize, Clone, PartialEq, Debug, Default)]
+#[serde(rename_all = "PascalCase", default)]
+pub(crate) struct WebSocketMessage {
+    pub(crate) with_acknowledge: bool,
+    #[serde(with = "base64_bytes")]
+    pub(crate) payload: Vec<u8>,
+    pub(crate) topic: String,
+    pub(crate) counter: u64,
+    #[serde(rename = "Type")]
+    pub(crate) message_type: i32,
+    pub(crate) version: u32,
+}
+
+#[derive(Clone, PartialEq, Debug)]
+pub enum OutportEvent {
+    SaveBlock(OutportBlock),
+    RevertIndexedBlock(OutportBlockData),
+    FinalizedBlock(OutportFinalizedBlock),
+}
+
+impl OutportEvent {
+    // Messages of other topics, such as rounds or ratings, are not decoded.
+    pub(crate) fn decode(topic: &str, payload: &[u8]) -> Option<Result<OutportEvent, serde_json::Error>> {
+        let event = match topic {
+            SAVE_BLOCK_TOPIC => serde_json::from_slice(payload).map(OutportEvent::SaveBlock),
+            REVERT_INDEXED_BLOCK_TOPIC => serde_json::from_slice(payload).map(OutportEvent::RevertIndexedBlock),
+            FINALIZED_BLOCK_TOPIC => serde_json::from_slice(payload).map(OutportEvent::FinalizedBlock),
+            _ => return None,
+        };
+
+        Some(event)
+    }
+}
+
+// Byte fields are kept base64 encoded, as sent by the node.
+#[derive(Deserialize, Clone, PartialEq, Debug, Default)]
+#[serde(rename_all = "camelCase", default)]
+pub struct OutportBlockData {
+    #[serde(rename = "shardID")]
+    pub shard_id: u32,
+    pub header_type: String,
+    pub header_hash: String,
+}
+
+#[derive(Deserialize, Clone, PartialEq, Debug, Default)]
+#[serde(rename_all = "camelCase", default)]
+pub struct OutportFinalizedBlock {
+    #[serde(rename = "shardID")]
+    pub shard_id: u32,
+    pub header_hash: String,
+}
+
+#[derive(Deserialize, Clone, PartialEq, Debug, Default)]
+#[serde(rename_all = "camelCase", default)]
+pub struct OutportEventLog {
+    pub address: String,
+    pub identifier: String,
+    pub topics: Vec<String>,
+    pub data: String,
+    pub additional_data: Vec<String>,
+}
+
+impl OutportEventLog {
+    pub fn decoded_identifier(&self) -> String {
+        String::from_utf8_lossy(&BASE64.decode(&self.identifier).unwrap_or_default()).into_owned()
+    }
+
+    // Undecodable topics are returned empty, so indexes are preserved.
+    pub fn decoded_topics(&self) -> Vec<Vec<u8>> {
+        self.topics
+            .iter()
+            .map(|topic| BASE64.decode(topic).unwrap_or_default())
+            .collect()
+    }
+
+    pub fn decoded_data(&self) -> Vec<u8> {
+        BASE64.decode(&self.data).unwrap_or_default()
+    }
+}
+
+#[derive(Deserialize, Clone, PartialEq, Debug, Default)]
+#[serde(rename_all = "camelCase", default)]
+pub struct OutportLog {
+    pub address: String,
+    pub events: Vec<OutportEventLog>,
+}
+
+#[derive(Deserialize, Clone, PartialEq, Debug, Default)]
+#[serde(rename_all = "camelCase", default)]
+pub struct OutportLogData {
+    pub tx_hash: String,
+    pub log: Option<OutportLog>,
+}
+
+#[derive(Deserialize, Clone, PartialEq, Debug, Default)]
+#[serde(rename_all = "camelCase", default)]
+pub struct OutportTransaction {
+    pub nonce: u64,
+    pub sender: String,
+    pub receiver: String,
+    pub gas_price: u64,
+    pub gas_limit: u64,
+    pub data: String,
+}
+
+#[derive(Deserialize, Clone, PartialEq, Debug, Default)]
+#[serde(rename_all = "camelCase", default)]
+pub struct OutportTransactionInfo {
+    pub transaction: OutportTransaction,
+    pub execution_order: u32,
+}
+
+#[derive(Deserialize, Clone, PartialEq, Debug, Default)]
+#[serde(rename_all = "camelCase", default)]
+pub struct OutportTransactionPool {
+    pub transactions: HashMap<String, OutportTransactionInfo>,
+    pub logs: Vec<OutportLogData>,
+}
+
+#[derive(Deserialize, Clone, PartialEq, Debug, Default)]
+#[serde(rename_all = "camelCase", default)]
+pub struct OutportBlock {
+    #[serde(rename = "shardID")]
+    pub shard_id: u32,
+    pub block_data: Option<OutportBlockData>,
+    pub transaction_pool: Option<OutportTransactionPool>,
+    pub number_of_shards: u32,
+    pub highest_final_block_nonce: u64,
+    pub highest_final_block_hash: String,
+}
+
+mod base64_bytes {
+    use base64::Engine;
+    use base64::engine::general_purpose::STANDARD as BASE64;
+    use serde::{Deserialize, Deserializer, Serializer};
+
+    pub(crate) fn serialize<S: Serializer>(bytes: &[u8], serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&BASE64.encode(bytes))
+    }
+
+    pub(crate) fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Vec<u8>, D::Error> {
+        let encoded = Option::<String>::deserialize(deserializer)?.unwrap_or_default();
+
+        BASE64.decode(encoded).map_err(serde::de::Error::custom)
+    }
+}
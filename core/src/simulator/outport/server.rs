@@ -0,0 +1,174 @@
+use std::net::SocketAddr;
+use std::pin::Pin;
+use std::sync::{Arc, Mutex};
+
+use futures_util::{SinkExt, Stream, StreamExt};
+use tokio::net::{TcpListener, TcpStream};
+use tokio::sync::broadcast;
+use tokio::sync::broadcast::error::RecvError;
+use tokio::task::JoinHandle;
+use tokio_tungstenite::tungstenite::Message;
+
+use crate::error::lib::LibError;
+use crate::error::simulator::SimulatorError;
+use crate::simulator::outport::model::{ACK_MESSAGE_TYPE, OutportEvent, PAYLOAD_MESSAGE_TYPE, WebSocketMessage};
+use crate::utils::local_server::bind_local_listener;
+
+const OUTPORT_EVENTS_CHANNEL_CAPACITY: usize = 4096;
+
+pub type OutportStream = Pin<Box<dyn Stream<Item = OutportEvent> + Send>>;
+
+struct RunningOutportServer {
+    address: SocketAddr,
+    events: broadcast::Sender<OutportEvent>,
+    task: JoinHandle<()>,
+}
+
+// The server outlives simulator restarts, so the nodes' config keeps pointing at it.
+#[derive(Clone, Default)]
+pub(crate) struct OutportServer {
+    running: Arc<Mutex<Option<RunningOutportServer>>>,
+}
+
+impl OutportServer {
+    pub(crate) fn start(&self) -> Result<String, LibError> {
+        let mut running = self.running.lock().unwrap();
+
+        if let Some(running) = running.as_ref() {
+            return Ok(running.address.to_string());
+        }
+
+        let Some((listener, address)) = bind_local_listener() else {
+            return Err(SimulatorError::CannotStartOutportServer.into());
+        };
+
+        let Ok(listener) = TcpListener::from_std(listener) else {
+            return Err(SimulatorError::CannotStartOutportServer.into());
+        };
+
+        let events = broadcast::channel(OUTPORT_EVENTS_CHANNEL_CAPACITY).0;
+        let task = tokio::spawn(accept_connections(listener, events.clone()));
+
+        *running = Some(RunningOutportServer { address, events, task });
+
+        Ok(address.to_string())
+    }
+
+    pub(crate) fn subscribe(&self) -> Option<OutportStream> {
+        let receiver = self.running.lock().unwrap().as_ref()?.events.subscribe();
+
+        let stream = futures_util::stream::unfold(receiver, |mut receiver| async move {
+            loop {
+                match receiver.recv().await {
+                    Ok(event) => return Some((event, receiver)),
+                    Err(RecvError::Lagged(_)) => continue,
+                    Err(RecvError::Closed) => return None,
+                }
+            }
+        });
+
+        Some(Box::pin(stream))
+    }
+
+    pub(crate) fn stop(&self) {
+        if let Some(running) = self.running.lock().unwrap().take() {
+            running.task.abort();
+        }
+    }
+}
+
+async fn accept_connections(listener: TcpListener, events: broadcast::Sender<OutportEvent>) {
+    // Every node of the simulator opens its own connection.
+    while let Ok((stream, _)) = listener.accept().await {
+        tokio::spawn(handle_connection(stream, events.clone()));
+    }
+}
+
+async fn handle_connection(stream: TcpStream, events: broadcast::Sender<OutportEvent>) {
+    let Ok(mut websocket) = tokio_tungstenite::accept_async(stream).await else {
+        return;
+    };
+
+    while let Some(Ok(message)) = websocket.next().await {
+        let bytes = match message {
+            Message::Binary(bytes) => bytes,
+            Message::Text(text) => text.into_bytes(),
+            Message::Close(_) => break,
+            _ => continue,
+        };
+
+        let Ok(message) = serde_json::from_slice::<WebSocketMessage>(&bytes) else {
+            continue;
+        };
+
+        if message.message_type != PAYLOAD_MESSAGE_TYPE {
+            continue;
+        }
+
+        if let Some(Ok(event)) = OutportEvent::decode(&message.topic, &message.payload) {
+            let _ = events.send(event); // There might be no subscriber.
+        }
+
+        // Acknowledging even the payloads that cannot be decoded, otherwise the node would stop producing blocks.
+        if message.with_acknowledge {
+            let ack = WebSocketMessage {
+                topic: message.topic,
+                counter: message.counter,
+                message_type: ACK_MESSAGE_TYPE,
+                version: message.version,
+                ..Default::default()
+            };
+
+            let Ok(ack) = serde_json::to_vec(&ack) else {
+                continue;
+            };
+
+            if websocket.send(Message::Binary(ack)).await.is_err() {
+                break;
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use futures_util::{SinkExt, StreamExt};
+    use tokio_tungstenite::tungstenite::Message;
+
+    use crate::simulator::outport::model::{ACK_MESSAGE_TYPE, FINALIZED_BLOCK_TOPIC, OutportEvent, OutportFinalizedBlock, PAYLOAD_MESSAGE_TYPE, WebSocketMessage};
+    use crate::simulator::outport::server::OutportServer;
+
+    #[tokio::test]
+    async fn test_payloads_are_streamed_and_acknowledged() {
+        let server = OutportServer::default();
+        let address = server.start().unwrap();
+        let mut stream = server.subscribe().unwrap();
+
+        assert_eq!(server.start().unwrap(), address);
+
+        let (mut client, _) = tokio_tungstenite::connect_async(format!("ws://{address}/save")).await.unwrap();
+
+        let message = WebSocketMessage {
+            with_acknowledge: true,
+            payload: br#"{"shardID":1,"headerHash":"aGFzaA=="}"#.to_vec(),
+            topic: FINALIZED_BLOCK_TOPIC.to_string(),
+            counter: 7,
+            message_type: PAYLOAD_MESSAGE_TYPE,
+            version: 1,
+        };
+        client.send(Message::Binary(serde_json::to_vec(&message).unwrap())).await.unwrap();
+
+        let Some(Ok(Message::Binary(ack))) = client.next().await else {
+            panic!("no acknowledgement received");
+        };
+        let ack = serde_json::from_slice::<WebSocketMessage>(&ack).unwrap();
+
+        let expected_event = OutportEvent::FinalizedBlock(OutportFinalizedBlock { shard_id: 1, header_hash: "aGFzaA==".to_string() });
+
+        assert_eq!(ack.message_type, ACK_MESSAGE_TYPE);
+        assert_eq!(ack.counter, 7);
+        assert_eq!(stream.next().await, Some(expected_event));
+
+        server.stop();
+    }
+}
@@ -1,49 +1,38 @@
-use std::io::{BufRead, BufReader};
-use std::os::unix::process::ExitStatusExt;
-use std::process::Child;
-use std::thread;
+use tokio::sync::broadcast;
+use tokio::sync::broadcast::error::RecvError;
+
 use crate::error::lib::LibError;
 use crate::error::simulator::SimulatorError;
+use crate::simulator::supervisor::SimulatorEvent;
 
-pub struct SimulatorProcess(pub Child);
+// The child process is owned by the supervisor, which may replace it on restarts.
+pub struct SimulatorProcess {
+    pub(crate) events: broadcast::Receiver<SimulatorEvent>,
+}
 
 impl SimulatorProcess {
-    pub fn listen(mut self) -> Result<(), LibError> {
-        let Some(stdout) = self.0.stdout.take() else {
-            return Err(SimulatorError::StdoutAlreadyConsumed.into())
-        };
-
-        thread::spawn(move || {
-            let reader = BufReader::new(stdout);
-            for line in reader.lines() {
-                match line {
-                    Ok(line) => {
-                        println!("Line: {}", line);
-                        // Log the line or handle it as needed
-                    }
-                    Err(e) => {
-                        eprintln!("Error reading from stdout: {}", e);
-                        // Handle the error appropriately
-                    }
-                }
-            }
-        });
-
+    pub async fn listen(mut self) -> Result<(), LibError> {
         loop {
-            let Ok(exit_status) = self.0.wait() else {
-                return Err(SimulatorError::ProcessAlreadyFinished.into())
+            let exit = match self.events.recv().await {
+                Ok(SimulatorEvent::LogLine(line)) => {
+                    println!("Line: {}", line);
+                    continue;
+                },
+                Ok(SimulatorEvent::Exited(exit)) => exit,
+                Ok(_) | Err(RecvError::Lagged(_)) => continue,
+                Err(RecvError::Closed) => return Err(SimulatorError::ProcessAlreadyFinished.into()),
             };
 
-            if exit_status.success() {
+            if exit.code == Some(0) {
                 return Ok(());
-            } else {
-                if exit_status.signal() == Some(9) {
-                    println!("Process has been killed.");
-                    return Ok(())
-                }
+            }
 
-                return Err(SimulatorError::ProcessExitedWithErrorCode { code: exit_status.code(), signal: exit_status.signal() }.into());
+            if exit.signal == Some(9) {
+                println!("Process has been killed.");
+                return Ok(())
             }
+
+            return Err(SimulatorError::ProcessExitedWithErrorCode { code: exit.code, signal: exit.signal }.into());
         }
     }
-}
\ No newline at end of file
+}
@@ -0,0 +1,182 @@
+use std::fs;
+use std::path::Path;
+use std::time::Duration;
+
+use toml::Value;
+
+use crate::error::lib::LibError;
+use crate::error::node_config::NodeConfigError;
+use crate::simulator::api_routes::ApiRoutesConfig;
+use crate::simulator::node_config::{get_section, update_config_file};
+
+const PROXY_CONFIG_FOLDER: &str = "config/proxy/config";
+const MAIN_CONFIG_NAME: &str = "config.toml";
+const DEFAULT_API_CONFIG_PATH: &str = "apiConfig/v1_0.toml";
+const NEXT_API_CONFIG_PATH: &str = "apiConfig/v_next.toml";
+
+#[derive(Clone, Copy, PartialEq, Debug, Default)]
+pub enum ProxyApiVersion {
+    #[default]
+    V1_0,
+    VNext,
+}
+
+// The proxy's port is always the simulator's `server_port`.
+#[derive(Clone, PartialEq, Debug, Default)]
+pub struct ProxyOptions {
+    request_timeout: Option<Duration>,
+    allow_entire_tx_pool_fetch: Option<bool>,
+    api_logging_enabled: Option<bool>,
+    api_logging_threshold: Option<Duration>,
+    api_version: ProxyApiVersion,
+    routes: ApiRoutesConfig,
+}
+
+impl ProxyOptions {
+    pub fn new() -> ProxyOptions {
+        Self::default()
+    }
+
+    // Rounded down to the second.
+    pub fn with_request_timeout(mut self, request_timeout: Duration) -> Self {
+        self.request_timeout = Some(request_timeout);
+
+        self
+    }
+
+    pub fn allow_entire_tx_pool_fetch(mut self) -> Self {
+        self.allow_entire_tx_pool_fetch = Some(true);
+
+        self
+    }
+
+    pub fn with_api_logging(mut self, threshold: Duration) -> Self {
+        self.api_logging_enabled = Some(true);
+        self.api_logging_threshold = Some(threshold);
+
+        self
+    }
+
+    pub fn without_api_logging(mut self) -> Self {
+        self.api_logging_enabled = Some(false);
+
+        self
+    }
+
+    pub fn with_api_version(mut self, api_version: ProxyApiVersion) -> Self {
+        self.api_version = api_version;
+
+        self
+    }
+
+    pub fn enable_route(mut self, package: impl Into<String>, route: impl Into<String>) -> Self {
+        self.routes = self.routes.open_route(package, route);
+
+        self
+    }
+
+    pub fn disable_route(mut self, package: impl Into<String>, route: impl Into<String>) -> Self {
+        self.routes = self.routes.close_route(package, route);
+
+        self
+    }
+}
+
+pub(crate) fn apply_proxy_options(working_dir: &Path, options: &ProxyOptions) -> Result<(), LibError> {
+    let config_folder = working_dir.join(PROXY_CONFIG_FOLDER);
+
+    update_config_file(&config_folder.join(MAIN_CONFIG_NAME), |config, file_path| {
+        let general_settings = get_section(config, file_path, "GeneralSettings")?;
+
+        if let Some(request_timeout) = options.request_timeout {
+            general_settings.insert("RequestTimeoutSec".to_string(), Value::Integer(request_timeout.as_secs() as i64));
+        }
+
+        if let Some(allow_entire_tx_pool_fetch) = options.allow_entire_tx_pool_fetch {
+            general_settings.insert("AllowEntireTxPoolFetch".to_string(), Value::Boolean(allow_entire_tx_pool_fetch));
+        }
+
+        let api_logging = get_section(config, file_path, "ApiLogging")?;
+
+        if let Some(api_logging_enabled) = options.api_logging_enabled {
+            api_logging.insert("LoggingEnabled".to_string(), Value::Boolean(api_logging_enabled));
+        }
+
+        if let Some(api_logging_threshold) = options.api_logging_threshold {
+            api_logging.insert("ThresholdInMicroSeconds".to_string(), Value::Integer(api_logging_threshold.as_micros() as i64));
+        }
+
+        Ok(())
+    })?;
+
+    let default_api_config_path = config_folder.join(DEFAULT_API_CONFIG_PATH);
+
+    if options.api_version == ProxyApiVersion::VNext {
+        let next_api_config_path = config_folder.join(NEXT_API_CONFIG_PATH);
+
+        let Ok(_) = fs::copy(&next_api_config_path, &default_api_config_path) else {
+            return Err(NodeConfigError::CannotWriteFile { file_path: default_api_config_path.to_string_lossy().to_string() }.into());
+        };
+    }
+
+    if !options.routes.is_empty() {
+        update_config_file(&default_api_config_path, |config, file_path| options.routes.apply(config, file_path))?;
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::Path;
+    use std::time::Duration;
+
+    use crate::error::lib::LibError;
+    use crate::error::node_config::NodeConfigError;
+    use crate::simulator::node_config::{copy_bundled_config, read_config_file};
+    use crate::simulator::proxy_config::{apply_proxy_options, DEFAULT_API_CONFIG_PATH, PROXY_CONFIG_FOLDER, ProxyApiVersion, ProxyOptions};
+
+    fn find_route_open(working_dir: &Path, package: &str, route: &str) -> Option<bool> {
+        let config = read_config_file(&working_dir.join(PROXY_CONFIG_FOLDER).join(DEFAULT_API_CONFIG_PATH)).unwrap();
+
+        config["APIPackages"][package]["Routes"]
+            .as_array()?
+            .iter()
+            .find(|entry| entry["Name"].as_str() == Some(route))?
+            .get("Open")?
+            .as_bool()
+    }
+
+    #[test]
+    fn test_proxy_options_are_rendered() {
+        let working_dir = copy_bundled_config(PROXY_CONFIG_FOLDER);
+        let options = ProxyOptions::new()
+            .with_request_timeout(Duration::from_secs(5))
+            .allow_entire_tx_pool_fetch()
+            .with_api_logging(Duration::from_millis(10))
+            .with_api_version(ProxyApiVersion::VNext)
+            .enable_route("status", "/metrics")
+            .disable_route("address", "/:address/keys");
+
+        apply_proxy_options(working_dir.path(), &options).unwrap();
+
+        let config = read_config_file(&working_dir.path().join(PROXY_CONFIG_FOLDER).join("config.toml")).unwrap();
+
+        assert_eq!(config["GeneralSettings"]["RequestTimeoutSec"].as_integer(), Some(5));
+        assert_eq!(config["GeneralSettings"]["AllowEntireTxPoolFetch"].as_bool(), Some(true));
+        assert_eq!(config["ApiLogging"]["ThresholdInMicroSeconds"].as_integer(), Some(10000));
+        assert_eq!(find_route_open(working_dir.path(), "status", "/metrics"), Some(true));
+        assert_eq!(find_route_open(working_dir.path(), "status", "/prometheus-metrics"), Some(false));
+        assert_eq!(find_route_open(working_dir.path(), "address", "/:address/keys"), Some(false));
+    }
+
+    #[test]
+    fn test_unknown_route_is_an_error() {
+        let working_dir = copy_bundled_config(PROXY_CONFIG_FOLDER);
+        let options = ProxyOptions::new().disable_route("address", "/:address/unknown");
+
+        let result = apply_proxy_options(working_dir.path(), &options);
+
+        assert!(matches!(result, Err(LibError::NodeConfig(NodeConfigError::CannotFindRoute { .. }))));
+    }
+}
@@ -0,0 +1,190 @@
+use std::time::{Duration, Instant};
+
+use reqwest::Client;
+
+use crate::error::lib::LibError;
+use crate::error::readiness::ReadinessError;
+use crate::simulator::model::{get_network_config, get_network_status};
+use crate::simulator::shard::ShardId;
+use crate::simulator::supervisor::SimulatorSupervisor;
+
+pub(crate) const METACHAIN_SHARD_ID: u32 = 4294967295;
+
+#[derive(Clone, PartialEq, Debug)]
+pub struct ReadinessPolicy {
+    pub(crate) timeout: Duration,
+    pub(crate) initial_backoff: Duration,
+    pub(crate) max_backoff: Duration,
+    pub(crate) required_endpoints: Vec<String>,
+    pub(crate) check_shards_status: bool,
+    pub(crate) check_initial_epoch: bool,
+}
+
+impl ReadinessPolicy {
+    pub fn new() -> ReadinessPolicy {
+        Self::default()
+    }
+
+    pub fn with_timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = timeout;
+
+        self
+    }
+
+    pub fn with_backoff(mut self, initial: Duration, max: Duration) -> Self {
+        self.initial_backoff = initial;
+        self.max_backoff = max;
+
+        self
+    }
+
+    pub fn with_required_endpoint(mut self, endpoint: impl Into<String>) -> Self {
+        self.required_endpoints.push(endpoint.into());
+
+        self
+    }
+
+    pub fn without_shards_status_check(mut self) -> Self {
+        self.check_shards_status = false;
+
+        self
+    }
+
+    pub fn without_initial_epoch_check(mut self) -> Self {
+        self.check_initial_epoch = false;
+
+        self
+    }
+}
+
+impl Default for ReadinessPolicy {
+    fn default() -> Self {
+        Self {
+            timeout: Duration::from_secs(30),
+            initial_backoff: Duration::from_millis(10),
+            max_backoff: Duration::from_millis(500),
+            required_endpoints: vec!["/about".to_string(), "/network/config".to_string()],
+            check_shards_status: true,
+            check_initial_epoch: true,
+        }
+    }
+}
+
+pub(crate) async fn wait_to_be_ready(supervisor: &SimulatorSupervisor, server_port: u16, num_of_shards: u64, policy: &ReadinessPolicy) -> Result<(), LibError> {
+    let deadline = Instant::now() + policy.timeout;
+    let mut backoff = policy.initial_backoff;
+
+    let mut urls: Vec<String> = policy.required_endpoints
+        .iter()
+        .map(|endpoint| format!("http://localhost:{server_port}{endpoint}"))
+        .collect();
+
+    if policy.check_shards_status {
+        urls.extend(
+            (0..num_of_shards as u32)
+                .chain([METACHAIN_SHARD_ID])
+                .map(|shard| format!("http://localhost:{server_port}/network/status/{shard}"))
+        );
+    }
+
+    let client = Client::new();
+
+    loop {
+        check_process_is_running(supervisor)?;
+
+        let last_unready_url = find_first_unready_url(&client, &urls).await;
+
+        let Some(last_unready_url) = last_unready_url else {
+            break
+        };
+
+        if Instant::now() + backoff > deadline {
+            return Err(ReadinessError::TimedOut { timeout: policy.timeout, last_unready_url: Some(last_unready_url) }.into());
+        }
+
+        tokio::time::sleep(backoff).await;
+        backoff = (backoff * 2).min(policy.max_backoff);
+    }
+
+    if policy.check_shards_status {
+        let actual = get_network_config(server_port).await?.num_shards_without_meta as u64;
+
+        if actual != num_of_shards {
+            return Err(ReadinessError::ShardsCountMismatch { expected: num_of_shards, actual }.into());
+        }
+    }
+
+    Ok(())
+}
+
+pub(crate) async fn check_initial_epoch(server_port: u16, expected_epoch: u64) -> Result<(), LibError> {
+    let actual = get_network_status(server_port, ShardId::Metachain).await?.epoch_number;
+
+    if actual < expected_epoch {
+        return Err(ReadinessError::InitialEpochNotReached { expected: expected_epoch, actual }.into());
+    }
+
+    Ok(())
+}
+
+fn check_process_is_running(supervisor: &SimulatorSupervisor) -> Result<(), LibError> {
+    let Some(exit) = supervisor.get_exit() else {
+        return Ok(());
+    };
+
+    let error = ReadinessError::ProcessExitedDuringStartup {
+        code: exit.code,
+        signal: exit.signal,
+        last_log_lines: exit.last_log_lines,
+    };
+
+    Err(error.into())
+}
+
+async fn find_first_unready_url(client: &Client, urls: &[String]) -> Option<String> {
+    for url in urls {
+        let is_ready = match client.get(url).send().await {
+            Ok(response) => response.status().is_success(),
+            Err(_) => false,
+        };
+
+        if !is_ready {
+            return Some(url.clone());
+        }
+    }
+
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use std::process::{Command, Stdio};
+
+    use tokio::sync::broadcast;
+
+    use crate::error::lib::LibError;
+    use crate::error::readiness::ReadinessError;
+    use crate::ReadinessPolicy;
+    use crate::simulator::readiness::wait_to_be_ready;
+    use crate::simulator::supervisor::SimulatorSupervisor;
+
+    #[tokio::test]
+    async fn test_wait_to_be_ready_detects_process_exit() {
+        let child = Command::new("sh")
+            .args(["-c", "echo starting; echo crashed; exit 3"])
+            .stdout(Stdio::piped())
+            .spawn()
+            .unwrap();
+        let supervisor = SimulatorSupervisor::start(child, broadcast::channel(16).0);
+
+        let result = wait_to_be_ready(&supervisor, 1, 3, &ReadinessPolicy::new()).await;
+
+        let expected = ReadinessError::ProcessExitedDuringStartup {
+            code: Some(3),
+            signal: None,
+            last_log_lines: vec!["starting".to_string(), "crashed".to_string()],
+        };
+
+        assert_eq!(result, Err(LibError::Readiness(expected)));
+    }
+}
@@ -0,0 +1,17 @@
+use serde::Deserialize;
+
+#[derive(Deserialize, Clone, PartialEq, Debug)]
+pub struct About {
+    #[serde(rename = "appVersion", default)]
+    pub app_version: String,
+    #[serde(rename = "commitID", default)]
+    pub commit_id: String,
+}
+
+#[derive(Deserialize, Clone, Debug)]
+#[allow(dead_code)]
+pub struct AboutResponse {
+    pub data: Option<About>,
+    pub error: String,
+    pub code: String
+}
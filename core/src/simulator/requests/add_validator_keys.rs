@@ -0,0 +1,15 @@
+use serde::{Deserialize, Serialize};
+
+#[derive(Serialize, Clone, PartialEq, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct AddValidatorKeysRequest {
+    // base64(hex(secret key)), as in the validators' `.pem` files.
+    pub private_keys_base64: Vec<String>,
+}
+
+#[derive(Deserialize)]
+#[allow(dead_code)]
+pub struct AddValidatorKeysResponse {
+    pub error: String,
+    pub code: String
+}
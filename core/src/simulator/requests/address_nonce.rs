@@ -0,0 +1,14 @@
+use serde::Deserialize;
+
+#[derive(Deserialize, Clone, Debug)]
+pub struct AddressNonceResponseData {
+    pub nonce: u64
+}
+
+#[derive(Deserialize, Clone, Debug)]
+#[allow(dead_code)]
+pub struct AddressNonceResponse {
+    pub data: Option<AddressNonceResponseData>,
+    pub error: String,
+    pub code: String
+}
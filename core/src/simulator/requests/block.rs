@@ -0,0 +1,128 @@
+use serde::Deserialize;
+
+use crate::simulator::requests::transaction::TransactionOnNetwork;
+
+#[derive(Deserialize, Clone, PartialEq, Debug, Default)]
+#[serde(rename_all = "camelCase", default)]
+pub struct MiniBlock {
+    pub hash: String,
+    #[serde(rename = "type")]
+    pub kind: String,
+    pub processing_type: String,
+    pub construction_state: String,
+    pub source_shard: u32,
+    pub destination_shard: u32,
+    pub transactions: Vec<TransactionOnNetwork>,
+    pub receipts: Vec<TransactionOnNetwork>,
+}
+
+#[derive(Deserialize, Clone, PartialEq, Debug, Default)]
+#[serde(rename_all = "camelCase", default)]
+pub struct NotarizedBlock {
+    pub hash: String,
+    pub nonce: u64,
+    pub round: u64,
+    pub shard: u32,
+}
+
+#[derive(Deserialize, Clone, PartialEq, Debug, Default)]
+#[serde(rename_all = "camelCase", default)]
+pub struct Block {
+    pub nonce: u64,
+    pub round: u64,
+    pub epoch: u64,
+    pub shard: u32,
+    pub num_txs: u64,
+    pub hash: String,
+    pub prev_block_hash: String,
+    pub state_root_hash: String,
+    pub accumulated_fees: String,
+    pub developer_fees: String,
+    pub status: String,
+    pub timestamp: u64,
+    pub mini_blocks: Vec<MiniBlock>,
+    pub notarized_blocks: Vec<NotarizedBlock>,
+}
+
+#[derive(Deserialize, Clone, PartialEq, Debug, Default)]
+#[serde(rename_all = "camelCase", default)]
+pub struct HyperblockShardBlock {
+    pub hash: String,
+    pub nonce: u64,
+    pub round: u64,
+    pub shard: u32,
+    pub root_hash: String,
+    pub status: String,
+}
+
+#[derive(Deserialize, Clone, PartialEq, Debug, Default)]
+#[serde(rename_all = "camelCase", default)]
+pub struct Hyperblock {
+    pub hash: String,
+    pub prev_block_hash: String,
+    pub state_root_hash: String,
+    pub nonce: u64,
+    pub round: u64,
+    pub epoch: u64,
+    pub num_txs: u64,
+    pub accumulated_fees: String,
+    pub developer_fees: String,
+    pub accumulated_fees_in_epoch: String,
+    pub developer_fees_in_epoch: String,
+    pub timestamp: u64,
+    pub shard_blocks: Vec<HyperblockShardBlock>,
+    pub transactions: Vec<TransactionOnNetwork>,
+    pub status: String,
+}
+
+#[derive(Clone, PartialEq, Debug)]
+pub struct ProducedBlock {
+    pub shard: u32,
+    pub nonce: u64,
+    pub hash: String,
+}
+
+#[derive(Deserialize, Clone, Debug)]
+pub struct BlockResponseData {
+    pub block: Block
+}
+
+#[derive(Deserialize, Clone, Debug)]
+#[allow(dead_code)]
+pub struct BlockResponse {
+    pub data: Option<BlockResponseData>,
+    pub error: String,
+    pub code: String
+}
+
+#[derive(Deserialize, Clone, Debug)]
+pub struct HyperblockResponseData {
+    pub hyperblock: Hyperblock
+}
+
+#[derive(Deserialize, Clone, Debug)]
+#[allow(dead_code)]
+pub struct HyperblockResponse {
+    pub data: Option<HyperblockResponseData>,
+    pub error: String,
+    pub code: String
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::simulator::requests::block::HyperblockResponse;
+
+    #[test]
+    fn test_parse_hyperblock_response() {
+        let text = r#"{"data":{"hyperblock":{"hash":"aa","prevBlockHash":"bb","stateRootHash":"cc","nonce":42,"round":43,"epoch":2,"numTxs":1,"accumulatedFees":"0","developerFees":"0","timestamp":1700000000,"shardBlocks":[{"hash":"dd","nonce":40,"round":43,"shard":1,"rootHash":"ee","status":"on-chain"}],"transactions":[{"type":"normal","hash":"ff","nonce":7,"value":"1000","receiver":"erd1receiver","sender":"erd1sender","gasPrice":1000000000,"gasLimit":50000,"data":"aGVsbG8=","signature":"00","sourceShard":1,"destinationShard":0,"status":"success","chainID":"chain","version":1}],"status":"on-chain"}},"error":"","code":"successful"}"#;
+
+        let hyperblock = serde_json::from_str::<HyperblockResponse>(text).unwrap().data.unwrap().hyperblock;
+
+        assert_eq!(hyperblock.nonce, 42);
+        assert_eq!(hyperblock.shard_blocks[0].shard, 1);
+        assert_eq!(hyperblock.transactions[0].kind, "normal");
+        assert_eq!(hyperblock.transactions[0].data.as_deref(), Some("aGVsbG8="));
+        assert_eq!(hyperblock.transactions[0].chain_id, "chain");
+        assert_eq!(hyperblock.transactions[0].gas_used, 0);
+    }
+}
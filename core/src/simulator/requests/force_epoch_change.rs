@@ -0,0 +1,12 @@
+use serde::Deserialize;
+
+#[derive(Deserialize)]
+pub struct ForceEpochChangeResponseEmpty {}
+
+#[derive(Deserialize)]
+#[allow(dead_code)]
+pub struct ForceEpochChangeResponse {
+    pub data: ForceEpochChangeResponseEmpty,
+    pub error: String,
+    pub code: String
+}
@@ -4,6 +4,7 @@ use serde::Deserialize;
 pub struct GenerateBlocksResponseEmpty {}
 
 #[derive(Deserialize)]
+#[allow(dead_code)]
 pub struct GenerateBlocksResponse {
     pub data: GenerateBlocksResponseEmpty,
     pub error: String,
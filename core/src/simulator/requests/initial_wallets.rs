@@ -19,6 +19,7 @@ pub struct InitialWalletsResponseData {
 }
 
 #[derive(Deserialize, Clone, Debug)]
+#[allow(dead_code)]
 pub struct InitialWalletsResponse {
     pub data: Option<InitialWalletsResponseData>,
     pub error: String,
@@ -1,4 +1,20 @@
 pub(crate) mod generate_blocks;
 pub(crate) mod set_address_keys;
 pub(crate) mod set_state;
-pub(crate) mod initial_wallets;
\ No newline at end of file
+pub(crate) mod initial_wallets;
+pub(crate) mod network_status;
+pub(crate) mod network_config;
+pub(crate) mod force_epoch_change;
+pub(crate) mod network_economics;
+pub(crate) mod about;
+pub(crate) mod block;
+pub(crate) mod transaction;
+pub(crate) mod send_transaction;
+pub(crate) mod send_multiple_transactions;
+pub(crate) mod transaction_cost;
+pub(crate) mod simulate_transaction;
+pub(crate) mod address_nonce;
+pub(crate) mod node_status;
+pub(crate) mod add_validator_keys;
+pub(crate) mod validator_statistics;
+pub(crate) mod transaction_pool;
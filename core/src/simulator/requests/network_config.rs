@@ -0,0 +1,68 @@
+use serde::Deserialize;
+
+#[derive(Deserialize, Clone, PartialEq, Debug)]
+pub struct NetworkConfig {
+    #[serde(rename = "erd_chain_id")]
+    pub chain_id: String,
+    #[serde(rename = "erd_denomination")]
+    pub denomination: u32,
+    #[serde(rename = "erd_gas_per_data_byte")]
+    pub gas_per_data_byte: u64,
+    #[serde(rename = "erd_gas_price_modifier")]
+    pub gas_price_modifier: String,
+    #[serde(rename = "erd_min_gas_limit")]
+    pub min_gas_limit: u64,
+    #[serde(rename = "erd_min_gas_price")]
+    pub min_gas_price: u64,
+    #[serde(rename = "erd_min_transaction_version")]
+    pub min_transaction_version: u32,
+    #[serde(rename = "erd_extra_gas_limit_guarded_tx", default)]
+    pub extra_gas_limit_guarded_tx: u64,
+    #[serde(rename = "erd_num_shards_without_meta")]
+    pub num_shards_without_meta: u32,
+    #[serde(rename = "erd_num_nodes_in_shard")]
+    pub num_nodes_in_shard: u32,
+    #[serde(rename = "erd_num_metachain_nodes")]
+    pub num_metachain_nodes: u32,
+    #[serde(rename = "erd_shard_consensus_group_size")]
+    pub shard_consensus_group_size: u32,
+    #[serde(rename = "erd_meta_consensus_group_size")]
+    pub meta_consensus_group_size: u32,
+    #[serde(rename = "erd_round_duration")]
+    pub round_duration: u64,
+    #[serde(rename = "erd_rounds_per_epoch")]
+    pub rounds_per_epoch: u64,
+    #[serde(rename = "erd_start_time")]
+    pub start_time: u64,
+}
+
+#[derive(Deserialize, Clone, Debug)]
+pub struct NetworkConfigResponseData {
+    pub config: NetworkConfig
+}
+
+#[derive(Deserialize, Clone, Debug)]
+#[allow(dead_code)]
+pub struct NetworkConfigResponse {
+    pub data: Option<NetworkConfigResponseData>,
+    pub error: String,
+    pub code: String
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::simulator::requests::network_config::NetworkConfigResponse;
+
+    #[test]
+    fn test_parse_network_config_response() {
+        let text = r#"{"data":{"config":{"erd_adaptivity":"false","erd_chain_id":"chain","erd_denomination":18,"erd_extra_gas_limit_guarded_tx":50000,"erd_gas_per_data_byte":1500,"erd_gas_price_modifier":"0.01","erd_hysteresis":"0.000000","erd_latest_tag_software_version":"D1.7.0.0","erd_max_gas_per_transaction":600000000,"erd_meta_consensus_group_size":1,"erd_min_gas_limit":50000,"erd_min_gas_price":1000000000,"erd_min_transaction_version":1,"erd_num_metachain_nodes":1,"erd_num_nodes_in_shard":1,"erd_num_shards_without_meta":3,"erd_rewards_top_up_gradient_point":"2000000000000000000000000","erd_round_duration":6000,"erd_rounds_per_epoch":20,"erd_shard_consensus_group_size":1,"erd_start_time":1700000000,"erd_top_up_factor":"0.500000"}},"error":"","code":"successful"}"#;
+
+        let config = serde_json::from_str::<NetworkConfigResponse>(text).unwrap().data.unwrap().config;
+
+        assert_eq!(config.chain_id, "chain");
+        assert_eq!(config.min_gas_price, 1000000000);
+        assert_eq!(config.num_shards_without_meta, 3);
+        assert_eq!(config.round_duration, 6000);
+        assert_eq!(config.rounds_per_epoch, 20);
+    }
+}
@@ -0,0 +1,33 @@
+use serde::Deserialize;
+
+// The amounts don't fit in a `u64`.
+#[derive(Deserialize, Clone, PartialEq, Debug)]
+pub struct NetworkEconomics {
+    #[serde(rename = "erd_dev_rewards")]
+    pub dev_rewards: String,
+    #[serde(rename = "erd_epoch_for_economics_data")]
+    pub epoch_for_economics_data: u64,
+    #[serde(rename = "erd_inflation")]
+    pub inflation: String,
+    #[serde(rename = "erd_total_base_staked_value")]
+    pub total_base_staked_value: String,
+    #[serde(rename = "erd_total_fees")]
+    pub total_fees: String,
+    #[serde(rename = "erd_total_supply")]
+    pub total_supply: String,
+    #[serde(rename = "erd_total_top_up_value")]
+    pub total_top_up_value: String,
+}
+
+#[derive(Deserialize, Clone, Debug)]
+pub struct NetworkEconomicsResponseData {
+    pub metrics: NetworkEconomics
+}
+
+#[derive(Deserialize, Clone, Debug)]
+#[allow(dead_code)]
+pub struct NetworkEconomicsResponse {
+    pub data: Option<NetworkEconomicsResponseData>,
+    pub error: String,
+    pub code: String
+}
@@ -0,0 +1,36 @@
+use serde::Deserialize;
+
+#[derive(Deserialize, Clone, PartialEq, Debug)]
+pub struct NetworkStatus {
+    #[serde(rename = "erd_current_round")]
+    pub current_round: u64,
+    #[serde(rename = "erd_epoch_number")]
+    pub epoch_number: u64,
+    #[serde(rename = "erd_highest_final_nonce")]
+    pub highest_final_nonce: u64,
+    #[serde(rename = "erd_nonce")]
+    pub nonce: u64,
+    #[serde(rename = "erd_nonce_at_epoch_start")]
+    pub nonce_at_epoch_start: u64,
+    #[serde(rename = "erd_nonces_passed_in_current_epoch")]
+    pub nonces_passed_in_current_epoch: u64,
+    #[serde(rename = "erd_round_at_epoch_start")]
+    pub round_at_epoch_start: u64,
+    #[serde(rename = "erd_rounds_passed_in_current_epoch")]
+    pub rounds_passed_in_current_epoch: u64,
+    #[serde(rename = "erd_rounds_per_epoch")]
+    pub rounds_per_epoch: u64,
+}
+
+#[derive(Deserialize, Clone, Debug)]
+pub struct NetworkStatusResponseData {
+    pub status: NetworkStatus
+}
+
+#[derive(Deserialize, Clone, Debug)]
+#[allow(dead_code)]
+pub struct NetworkStatusResponse {
+    pub data: Option<NetworkStatusResponseData>,
+    pub error: String,
+    pub code: String
+}
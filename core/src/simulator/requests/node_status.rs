@@ -0,0 +1,24 @@
+use serde::Deserialize;
+
+#[derive(Deserialize, Clone, PartialEq, Debug)]
+pub struct NodeStatus {
+    #[serde(rename = "erd_shard_id")]
+    pub shard_id: u32,
+    #[serde(rename = "erd_nonce", default)]
+    pub nonce: u64,
+    #[serde(rename = "erd_epoch_number", default)]
+    pub epoch_number: u64,
+}
+
+#[derive(Deserialize, Clone, Debug)]
+pub struct NodeStatusResponseData {
+    pub metrics: NodeStatus
+}
+
+#[derive(Deserialize, Clone, Debug)]
+#[allow(dead_code)]
+pub struct NodeStatusResponse {
+    pub data: Option<NodeStatusResponseData>,
+    pub error: String,
+    pub code: String
+}
@@ -0,0 +1,19 @@
+use std::collections::HashMap;
+use serde::Deserialize;
+
+#[derive(Deserialize, Clone, Debug)]
+#[serde(rename_all = "camelCase")]
+#[allow(dead_code)]
+pub struct SendMultipleTransactionsResponseData {
+    pub num_of_sent_txs: u64,
+    // Rejected transactions are missing.
+    pub txs_hashes: HashMap<usize, String>
+}
+
+#[derive(Deserialize, Clone, Debug)]
+#[allow(dead_code)]
+pub struct SendMultipleTransactionsResponse {
+    pub data: Option<SendMultipleTransactionsResponseData>,
+    pub error: String,
+    pub code: String
+}
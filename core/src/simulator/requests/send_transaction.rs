@@ -0,0 +1,133 @@
+use serde::{Deserialize, Serialize};
+
+// Unset fields are filled from the network, the nonce from the sender's one tracked by the simulator.
+#[derive(Clone, PartialEq, Debug)]
+pub struct Transaction {
+    pub sender: String,
+    pub receiver: String,
+    pub value: String,
+    // Raw data, it is base64 encoded when sent.
+    pub data: Option<String>,
+    pub nonce: Option<u64>,
+    pub gas_price: Option<u64>,
+    pub gas_limit: Option<u64>,
+    pub chain_id: Option<String>,
+    pub version: Option<u32>,
+    pub options: u32,
+    pub signature: Option<String>,
+}
+
+impl Transaction {
+    pub fn new(sender: impl Into<String>, receiver: impl Into<String>) -> Transaction {
+        Transaction {
+            sender: sender.into(),
+            receiver: receiver.into(),
+            value: "0".to_string(),
+            data: None,
+            nonce: None,
+            gas_price: None,
+            gas_limit: None,
+            chain_id: None,
+            version: None,
+            options: 0,
+            signature: None,
+        }
+    }
+
+    pub fn with_value(mut self, value: impl Into<String>) -> Transaction {
+        self.value = value.into();
+
+        self
+    }
+
+    pub fn with_data(mut self, data: impl Into<String>) -> Transaction {
+        self.data = Some(data.into());
+
+        self
+    }
+
+    pub fn with_nonce(mut self, nonce: u64) -> Transaction {
+        self.nonce = Some(nonce);
+
+        self
+    }
+
+    pub fn with_gas_price(mut self, gas_price: u64) -> Transaction {
+        self.gas_price = Some(gas_price);
+
+        self
+    }
+
+    pub fn with_gas_limit(mut self, gas_limit: u64) -> Transaction {
+        self.gas_limit = Some(gas_limit);
+
+        self
+    }
+
+    pub fn with_auto_gas_limit(mut self) -> Transaction {
+        self.gas_limit = None;
+
+        self
+    }
+
+    pub fn with_chain_id(mut self, chain_id: impl Into<String>) -> Transaction {
+        self.chain_id = Some(chain_id.into());
+
+        self
+    }
+
+    pub fn with_version(mut self, version: u32) -> Transaction {
+        self.version = Some(version);
+
+        self
+    }
+
+    pub fn with_options(mut self, options: u32) -> Transaction {
+        self.options = options;
+
+        self
+    }
+
+    pub fn with_signature(mut self, signature: impl Into<String>) -> Transaction {
+        self.signature = Some(signature.into());
+
+        self
+    }
+}
+
+#[derive(Serialize, Clone, PartialEq, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct TransactionRequest {
+    pub nonce: u64,
+    pub value: String,
+    pub receiver: String,
+    pub sender: String,
+    pub gas_price: u64,
+    pub gas_limit: u64,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub data: Option<String>,
+    pub signature: String,
+    #[serde(rename = "chainID")]
+    pub chain_id: String,
+    pub version: u32,
+    #[serde(skip_serializing_if = "is_zero")]
+    pub options: u32,
+}
+
+fn is_zero(value: &u32) -> bool {
+    *value == 0
+}
+
+#[derive(Deserialize, Clone, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct SendTransactionResponseData {
+    pub tx_hash: String
+}
+
+#[derive(Deserialize, Clone, Debug)]
+#[allow(dead_code)]
+pub struct SendTransactionResponse {
+    pub data: Option<SendTransactionResponseData>,
+    pub error: String,
+    pub code: String
+}
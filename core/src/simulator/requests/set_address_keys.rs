@@ -4,6 +4,7 @@ use serde::Deserialize;
 pub struct SetAddressKeysEmpty {}
 
 #[derive(Deserialize)]
+#[allow(dead_code)]
 pub struct SetAddressKeysResponse {
     pub data: SetAddressKeysEmpty,
     pub error: String,
@@ -5,17 +5,19 @@ use serde::{Deserialize, Serialize};
 pub struct SetStateResponseEmpty {}
 
 #[derive(Deserialize)]
+#[allow(dead_code)]
 pub struct SetStateResponse {
     pub data: SetStateResponseEmpty,
     pub error: String,
     pub code: String
 }
 
-#[derive(Serialize, Clone, PartialEq, Debug)]
+#[derive(Serialize, Clone, PartialEq, Debug, Default)]
 #[serde(rename_all = "camelCase")]
 pub struct SetStateAddress {
     pub address: Option<String>,
     pub balance: Option<String>,
+    pub nonce: Option<u64>,
     pub code: Option<String>,
     pub root_hash: Option<String>,
     pub code_metadata: Option<String>,
@@ -42,6 +44,12 @@ impl SetStateAddress {
         self
     }
 
+    pub fn with_nonce(mut self, nonce: u64) -> SetStateAddress {
+        self.nonce = Some(nonce);
+
+        self
+    }
+
     pub fn with_code(mut self, code: String) -> SetStateAddress {
         self.code = Some(code);
 
@@ -85,22 +93,6 @@ impl SetStateAddress {
     }
 }
 
-impl Default for SetStateAddress {
-    fn default() -> Self {
-        Self {
-            address: None,
-            balance: None,
-            code: None,
-            root_hash: None,
-            code_metadata: None,
-            code_hash: None,
-            developer_reward: None,
-            owner_address: None,
-            keys: None,
-        }
-    }
-}
-
 #[cfg(test)]
 mod tests {
     use crate::SetStateAddress;
@@ -113,6 +105,7 @@ mod tests {
         let expected = SetStateAddress {
             address: Some("test".to_string()),
             balance: None,
+            nonce: None,
             code: None,
             root_hash: None,
             code_metadata: None,
@@ -133,6 +126,28 @@ mod tests {
         let expected = SetStateAddress {
             address: None,
             balance: Some("100".to_string()),
+            nonce: None,
+            code: None,
+            root_hash: None,
+            code_metadata: None,
+            code_hash: None,
+            developer_reward: None,
+            owner_address: None,
+            keys: None,
+        };
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_with_nonce() {
+        let result = SetStateAddress::new()
+            .with_nonce(5);
+
+        let expected = SetStateAddress {
+            address: None,
+            balance: None,
+            nonce: Some(5),
             code: None,
             root_hash: None,
             code_metadata: None,
@@ -153,6 +168,7 @@ mod tests {
         let expected = SetStateAddress {
             address: None,
             balance: None,
+            nonce: None,
             code: Some("test".to_string()),
             root_hash: None,
             code_metadata: None,
@@ -173,6 +189,7 @@ mod tests {
         let expected = SetStateAddress {
             address: None,
             balance: None,
+            nonce: None,
             code: None,
             root_hash: Some("test".to_string()),
             code_metadata: None,
@@ -193,6 +210,7 @@ mod tests {
         let expected = SetStateAddress {
             address: None,
             balance: None,
+            nonce: None,
             code: None,
             root_hash: None,
             code_metadata: Some("test".to_string()),
@@ -213,6 +231,7 @@ mod tests {
         let expected = SetStateAddress {
             address: None,
             balance: None,
+            nonce: None,
             code: None,
             root_hash: None,
             code_metadata: None,
@@ -233,6 +252,7 @@ mod tests {
         let expected = SetStateAddress {
             address: None,
             balance: None,
+            nonce: None,
             code: None,
             root_hash: None,
             code_metadata: None,
@@ -253,6 +273,7 @@ mod tests {
         let expected = SetStateAddress {
             address: None,
             balance: None,
+            nonce: None,
             code: None,
             root_hash: None,
             code_metadata: None,
@@ -0,0 +1,86 @@
+use std::collections::HashMap;
+use serde::Deserialize;
+
+use crate::simulator::requests::transaction::SmartContractResult;
+use crate::utils::amount::deserialize_amount;
+
+#[derive(Deserialize, Clone, PartialEq, Debug, Default)]
+#[serde(rename_all = "camelCase", default)]
+pub struct Receipt {
+    #[serde(deserialize_with = "deserialize_amount")]
+    pub value: String,
+    pub sender: String,
+    pub data: String,
+    pub tx_hash: String,
+}
+
+#[derive(Deserialize, Clone, PartialEq, Debug, Default)]
+#[serde(rename_all = "camelCase", default)]
+pub struct SimulationResults {
+    pub status: String,
+    pub fail_reason: String,
+    pub sc_results: Option<HashMap<String, SmartContractResult>>,
+    pub receipts: Option<HashMap<String, Receipt>>,
+    pub hash: String,
+}
+
+// An intra-shard transaction only has `sender_shard` results.
+#[derive(Clone, PartialEq, Debug)]
+pub struct TransactionSimulation {
+    pub sender_shard: SimulationResults,
+    pub receiver_shard: Option<SimulationResults>,
+}
+
+#[derive(Deserialize, Clone, Debug)]
+#[serde(untagged)]
+pub enum TransactionSimulationResponseData {
+    #[serde(rename_all = "camelCase")]
+    CrossShard {
+        sender_shard: SimulationResults,
+        receiver_shard: SimulationResults,
+    },
+    IntraShard(SimulationResults),
+}
+
+impl From<TransactionSimulationResponseData> for TransactionSimulation {
+    fn from(value: TransactionSimulationResponseData) -> Self {
+        match value {
+            TransactionSimulationResponseData::CrossShard { sender_shard, receiver_shard } => {
+                TransactionSimulation { sender_shard, receiver_shard: Some(receiver_shard) }
+            },
+            TransactionSimulationResponseData::IntraShard(results) => {
+                TransactionSimulation { sender_shard: results, receiver_shard: None }
+            },
+        }
+    }
+}
+
+#[derive(Deserialize, Clone, Debug)]
+#[allow(dead_code)]
+pub struct TransactionSimulationResponse {
+    pub data: Option<TransactionSimulationResponseData>,
+    pub error: String,
+    pub code: String
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::simulator::requests::simulate_transaction::{TransactionSimulation, TransactionSimulationResponse};
+
+    #[test]
+    fn test_parse_intra_and_cross_shard_simulations() {
+        let intra_shard = r#"{"data":{"status":"success","failReason":"","scResults":{"dd":{"nonce":7,"gasLimit":50000,"value":18446744073709551617}},"receipts":{"aa":{"value":1000,"sender":"erd1sender","data":"refund","txHash":"bb"}},"hash":"cc"},"error":"","code":"successful"}"#;
+        let cross_shard = r#"{"data":{"senderShard":{"status":"success","hash":"cc"},"receiverShard":{"status":"fail","failReason":"insufficient funds","hash":"cc"}},"error":"","code":"successful"}"#;
+
+        let intra_shard: TransactionSimulation = serde_json::from_str::<TransactionSimulationResponse>(intra_shard).unwrap().data.unwrap().into();
+        let cross_shard: TransactionSimulation = serde_json::from_str::<TransactionSimulationResponse>(cross_shard).unwrap().data.unwrap().into();
+
+        assert_eq!(intra_shard.sender_shard.status, "success");
+        assert_eq!(intra_shard.sender_shard.receipts.unwrap()["aa"].value, "1000");
+        assert_eq!(intra_shard.sender_shard.sc_results.as_ref().unwrap()["dd"].nonce, 7);
+        assert_eq!(intra_shard.sender_shard.sc_results.as_ref().unwrap()["dd"].value, "18446744073709551617");
+        assert_eq!(intra_shard.receiver_shard, None);
+        assert_eq!(cross_shard.sender_shard.status, "success");
+        assert_eq!(cross_shard.receiver_shard.unwrap().fail_reason, "insufficient funds");
+    }
+}
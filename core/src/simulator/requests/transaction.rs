@@ -0,0 +1,248 @@
+use base64::Engine;
+use base64::engine::general_purpose::STANDARD as BASE64;
+use num_bigint::BigUint;
+use serde::Deserialize;
+
+use crate::utils::amount::deserialize_amount;
+
+const SIGNAL_ERROR_EVENT: &str = "signalError";
+const INTERNAL_VM_ERRORS_EVENT: &str = "internalVMErrors";
+const WRITE_LOG_EVENT: &str = "writeLog";
+const ESDT_TRANSFER_EVENTS: [&str; 3] = ["ESDTTransfer", "ESDTNFTTransfer", "MultiESDTNFTTransfer"];
+const OK_RETURN_CODE: &str = "6f6b";
+
+#[derive(Deserialize, Clone, PartialEq, Debug, Default)]
+#[serde(rename_all = "camelCase", default)]
+pub struct Event {
+    pub address: String,
+    pub identifier: String,
+    pub topics: Vec<Option<String>>,
+    pub data: Option<String>,
+    pub additional_data: Vec<Option<String>>,
+}
+
+impl Event {
+    // Undecodable topics are returned empty, so indexes are preserved.
+    pub fn decoded_topics(&self) -> Vec<Vec<u8>> {
+        self.topics
+            .iter()
+            .map(|topic| decode_base64(topic.as_deref()).unwrap_or_default())
+            .collect()
+    }
+
+    pub fn decoded_data(&self) -> Option<Vec<u8>> {
+        decode_base64(self.data.as_deref())
+    }
+}
+
+#[derive(Deserialize, Clone, PartialEq, Debug, Default)]
+#[serde(rename_all = "camelCase", default)]
+pub struct TransactionLogs {
+    pub address: String,
+    pub events: Vec<Event>,
+}
+
+#[derive(Deserialize, Clone, PartialEq, Debug, Default)]
+#[serde(rename_all = "camelCase", default)]
+pub struct SmartContractResult {
+    pub hash: String,
+    pub nonce: u64,
+    #[serde(deserialize_with = "deserialize_amount")]
+    pub value: String,
+    pub receiver: String,
+    pub sender: String,
+    // Unlike the transaction's one, this data is not base64 encoded.
+    pub data: String,
+    pub prev_tx_hash: String,
+    pub original_tx_hash: String,
+    pub gas_limit: u64,
+    pub gas_price: u64,
+    pub call_type: u32,
+    pub return_message: String,
+    pub operation: String,
+    pub function: String,
+    pub logs: Option<TransactionLogs>,
+}
+
+#[derive(Clone, PartialEq, Debug)]
+pub struct EsdtTransfer {
+    pub token_identifier: String,
+    pub nonce: u64,
+    pub amount: String,
+}
+
+#[derive(Deserialize, Clone, PartialEq, Debug, Default)]
+#[serde(rename_all = "camelCase", default)]
+pub struct TransactionOnNetwork {
+    #[serde(rename = "type")]
+    pub kind: String,
+    pub hash: String,
+    pub nonce: u64,
+    pub round: u64,
+    pub epoch: u64,
+    pub value: String,
+    pub receiver: String,
+    pub sender: String,
+    pub gas_price: u64,
+    pub gas_limit: u64,
+    pub gas_used: u64,
+    pub data: Option<String>,
+    pub signature: String,
+    pub source_shard: u32,
+    pub destination_shard: u32,
+    pub block_nonce: u64,
+    pub block_hash: String,
+    pub miniblock_type: String,
+    pub miniblock_hash: String,
+    pub hyperblock_nonce: u64,
+    pub hyperblock_hash: String,
+    pub timestamp: u64,
+    pub status: String,
+    pub operation: String,
+    pub function: String,
+    pub initially_paid_fee: String,
+    pub fee: String,
+    #[serde(rename = "chainID")]
+    pub chain_id: String,
+    pub version: u32,
+    pub options: u32,
+    #[serde(rename = "smartContractResults")]
+    pub smart_contract_results: Vec<SmartContractResult>,
+    pub logs: Option<TransactionLogs>,
+}
+
+impl TransactionOnNetwork {
+    pub fn events(&self) -> Vec<&Event> {
+        self.logs
+            .iter()
+            .chain(self.smart_contract_results.iter().filter_map(|scr| scr.logs.as_ref()))
+            .flat_map(|logs| logs.events.iter())
+            .collect()
+    }
+
+    pub fn find_events(&self, identifier: &str) -> Vec<&Event> {
+        self.events()
+            .into_iter()
+            .filter(|event| event.identifier == identifier)
+            .collect()
+    }
+
+    // A contract call signaling an error is not a success, even if the transaction was executed.
+    pub fn is_success(&self) -> bool {
+        self.status == "success" && self.find_events(SIGNAL_ERROR_EVENT).is_empty()
+    }
+
+    pub fn error_message(&self) -> Option<String> {
+        if let Some(event) = self.find_events(SIGNAL_ERROR_EVENT).first() {
+            if let Some(message) = event.decoded_topics().get(1) {
+                return Some(String::from_utf8_lossy(message).into_owned());
+            }
+        }
+
+        if let Some(scr) = self.smart_contract_results.iter().find(|scr| !scr.return_message.is_empty()) {
+            return Some(scr.return_message.clone());
+        }
+
+        self.find_events(INTERNAL_VM_ERRORS_EVENT)
+            .first()
+            .and_then(|event| event.decoded_data())
+            .map(|data| String::from_utf8_lossy(&data).into_owned())
+    }
+
+    // Read from the `@6f6b@...` smart contract result or, when there is none, from the `writeLog` event.
+    pub fn returned_data(&self) -> Option<Vec<Vec<u8>>> {
+        let from_scrs = self.smart_contract_results
+            .iter()
+            .find_map(|scr| parse_returned_data(&scr.data));
+
+        if from_scrs.is_some() {
+            return from_scrs;
+        }
+
+        self.find_events(WRITE_LOG_EVENT)
+            .into_iter()
+            .filter_map(|event| event.decoded_data())
+            .find_map(|data| parse_returned_data(&String::from_utf8_lossy(&data)))
+    }
+
+    pub fn esdt_transfers(&self) -> Vec<EsdtTransfer> {
+        self.events()
+            .into_iter()
+            .filter(|event| ESDT_TRANSFER_EVENTS.contains(&event.identifier.as_str()))
+            .flat_map(|event| parse_esdt_transfers(&event.decoded_topics()))
+            .collect()
+    }
+}
+
+fn decode_base64(value: Option<&str>) -> Option<Vec<u8>> {
+    BASE64.decode(value?).ok()
+}
+
+fn parse_returned_data(data: &str) -> Option<Vec<Vec<u8>>> {
+    let mut parts = data.split('@');
+
+    if parts.next() != Some("") || parts.next() != Some(OK_RETURN_CODE) {
+        return None;
+    }
+
+    parts.map(|part| hex::decode(part).ok()).collect()
+}
+
+// Topics are (identifier, nonce, amount) groups followed by the receiver.
+fn parse_esdt_transfers(topics: &[Vec<u8>]) -> Vec<EsdtTransfer> {
+    topics
+        .chunks_exact(3)
+        .map(|chunk| EsdtTransfer {
+            token_identifier: String::from_utf8_lossy(&chunk[0]).into_owned(),
+            nonce: chunk[1].iter().fold(0, |nonce, byte| (nonce << 8) | *byte as u64),
+            amount: BigUint::from_bytes_be(&chunk[2]).to_string(),
+        })
+        .collect()
+}
+
+#[derive(Deserialize, Clone, Debug)]
+pub struct TransactionResponseData {
+    pub transaction: TransactionOnNetwork
+}
+
+#[derive(Deserialize, Clone, Debug)]
+#[allow(dead_code)]
+pub struct TransactionResponse {
+    pub data: Option<TransactionResponseData>,
+    pub error: String,
+    pub code: String
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::simulator::requests::transaction::{EsdtTransfer, TransactionOnNetwork};
+
+    #[test]
+    fn test_failed_transaction_helpers() {
+        let text = r#"{"status":"success","smartContractResults":[{"value":1500,"data":"@75736572206572726f72","returnMessage":"wrong caller"}],"logs":{"address":"erd1contract","events":[{"address":"erd1contract","identifier":"signalError","topics":["AAAA","d3JvbmcgY2FsbGVy"],"data":null}]}}"#;
+
+        let transaction = serde_json::from_str::<TransactionOnNetwork>(text).unwrap();
+
+        assert!(!transaction.is_success());
+        assert_eq!(transaction.smart_contract_results[0].value, "1500");
+        assert_eq!(transaction.error_message(), Some("wrong caller".to_string()));
+        assert_eq!(transaction.returned_data(), None);
+    }
+
+    #[test]
+    fn test_successful_transaction_helpers() {
+        let text = r#"{"status":"success","smartContractResults":[{"data":"ESDTTransfer@5745474c442d616263646566@0de0b6b3a7640000","logs":{"events":[{"identifier":"ESDTTransfer","topics":["V0VHTEQtYWJjZGVm","","DeC2s6dkAAA=","cmVjdg=="]}]}}],"logs":{"events":[{"identifier":"ESDTNFTTransfer","topics":["V0VHTEQtYWJjZGVm","BQ==","DeC2s6dkAAA=","cmVjdg=="]},{"identifier":"writeLog","data":"QDZmNmJAMGE="}]}}"#;
+
+        let transaction = serde_json::from_str::<TransactionOnNetwork>(text).unwrap();
+
+        let expected_transfers = vec![
+            EsdtTransfer { token_identifier: "WEGLD-abcdef".to_string(), nonce: 5, amount: "1000000000000000000".to_string() },
+            EsdtTransfer { token_identifier: "WEGLD-abcdef".to_string(), nonce: 0, amount: "1000000000000000000".to_string() },
+        ];
+
+        assert!(transaction.is_success());
+        assert_eq!(transaction.error_message(), None);
+        assert_eq!(transaction.returned_data(), Some(vec![vec![10]]));
+        assert_eq!(transaction.esdt_transfers(), expected_transfers);
+    }
+}
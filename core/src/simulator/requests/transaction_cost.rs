@@ -0,0 +1,35 @@
+use std::collections::HashMap;
+use serde::Deserialize;
+
+use crate::simulator::requests::transaction::SmartContractResult;
+
+#[derive(Deserialize, Clone, PartialEq, Debug, Default)]
+#[serde(rename_all = "camelCase", default)]
+pub struct TransactionCost {
+    pub tx_gas_units: u64,
+    pub return_message: String,
+    pub smart_contract_results: Option<HashMap<String, SmartContractResult>>,
+}
+
+#[derive(Deserialize, Clone, Debug)]
+#[allow(dead_code)]
+pub struct TransactionCostResponse {
+    pub data: Option<TransactionCost>,
+    pub error: String,
+    pub code: String
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::simulator::requests::transaction_cost::TransactionCostResponse;
+
+    #[test]
+    fn test_parse_cost_with_smart_contract_results() {
+        let text = r#"{"data":{"txGasUnits":1500000,"returnMessage":"","smartContractResults":{"aa":{"nonce":1,"value":1000000000000000000,"receiver":"erd1receiver","sender":"erd1contract","data":"@6f6b"}}},"error":"","code":"successful"}"#;
+
+        let cost = serde_json::from_str::<TransactionCostResponse>(text).unwrap().data.unwrap();
+
+        assert_eq!(cost.tx_gas_units, 1500000);
+        assert_eq!(cost.smart_contract_results.unwrap()["aa"].value, "1000000000000000000");
+    }
+}
@@ -0,0 +1,32 @@
+use serde::Deserialize;
+
+#[derive(Deserialize, Clone, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct PoolTransactionFields {
+    pub nonce: u64
+}
+
+#[derive(Deserialize, Clone, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct PoolTransaction {
+    pub tx_fields: PoolTransactionFields
+}
+
+#[derive(Deserialize, Clone, Debug)]
+pub struct TransactionPool {
+    pub transactions: Vec<PoolTransaction>
+}
+
+#[derive(Deserialize, Clone, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct TransactionPoolResponseData {
+    pub tx_pool: TransactionPool
+}
+
+#[derive(Deserialize, Clone, Debug)]
+#[allow(dead_code)]
+pub struct TransactionPoolResponse {
+    pub data: Option<TransactionPoolResponseData>,
+    pub error: String,
+    pub code: String
+}
@@ -0,0 +1,43 @@
+use std::collections::HashMap;
+
+use serde::Deserialize;
+
+const ELIGIBLE_STATUS: &str = "eligible";
+
+#[derive(Deserialize, Clone, PartialEq, Debug, Default)]
+#[serde(rename_all = "camelCase", default)]
+pub struct ValidatorStatistics {
+    pub shard_id: u32,
+    pub validator_status: String,
+    pub rating: f32,
+    pub temp_rating: f32,
+    pub num_leader_success: u32,
+    pub num_leader_failure: u32,
+    pub num_validator_success: u32,
+    pub num_validator_failure: u32,
+    pub num_validator_ignored_signatures: u32,
+    pub total_num_leader_success: u32,
+    pub total_num_leader_failure: u32,
+    pub total_num_validator_success: u32,
+    pub total_num_validator_failure: u32,
+    pub total_num_validator_ignored_signatures: u32,
+}
+
+impl ValidatorStatistics {
+    pub fn is_eligible(&self) -> bool {
+        self.validator_status == ELIGIBLE_STATUS
+    }
+}
+
+#[derive(Deserialize, Clone, Debug)]
+pub struct ValidatorStatisticsResponseData {
+    pub statistics: HashMap<String, ValidatorStatistics>
+}
+
+#[derive(Deserialize, Clone, Debug)]
+#[allow(dead_code)]
+pub struct ValidatorStatisticsResponse {
+    pub data: Option<ValidatorStatisticsResponseData>,
+    pub error: String,
+    pub code: String
+}
@@ -0,0 +1,154 @@
+use std::fmt::{Display, Formatter};
+
+use crate::simulator::readiness::METACHAIN_SHARD_ID;
+use crate::utils::address::bech32_to_public_key;
+
+// Smart contracts deployed on the metachain have these bytes of their address set to zero.
+const NUM_INIT_CHARACTERS_FOR_SC_ADDRESS: usize = 10;
+const NUM_INIT_CHARACTERS_FOR_ON_METACHAIN_SC: usize = 5;
+const NUM_ZERO_BYTES_OF_SC_ADDRESS: usize = 8;
+
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Debug)]
+pub enum ShardId {
+    Shard(u32),
+    Metachain,
+}
+
+impl ShardId {
+    pub fn as_u32(&self) -> u32 {
+        match self {
+            ShardId::Shard(shard) => *shard,
+            ShardId::Metachain => METACHAIN_SHARD_ID,
+        }
+    }
+
+    // Computed like the nodes' shard coordinator does.
+    pub fn of_address(address: &str, num_of_shards: u32) -> Option<ShardId> {
+        let public_key = bech32_to_public_key(address)?;
+
+        ShardId::of_public_key(&public_key, num_of_shards)
+    }
+
+    pub fn of_public_key(public_key: &[u8], num_of_shards: u32) -> Option<ShardId> {
+        let last_byte = *public_key.last()?;
+
+        if num_of_shards == 0 {
+            return None;
+        }
+
+        if is_smart_contract_on_metachain(public_key) {
+            return Some(ShardId::Metachain);
+        }
+
+        let (mask_high, mask_low) = get_masks(num_of_shards);
+
+        let mut shard = last_byte as u32 & mask_high;
+        if shard > num_of_shards - 1 {
+            shard = last_byte as u32 & mask_low;
+        }
+
+        Some(ShardId::Shard(shard))
+    }
+}
+
+impl From<u32> for ShardId {
+    fn from(value: u32) -> Self {
+        match value {
+            METACHAIN_SHARD_ID => ShardId::Metachain,
+            shard => ShardId::Shard(shard),
+        }
+    }
+}
+
+impl From<ShardId> for u32 {
+    fn from(value: ShardId) -> Self {
+        value.as_u32()
+    }
+}
+
+impl Display for ShardId {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ShardId::Shard(shard) => write!(f, "{shard}"),
+            ShardId::Metachain => write!(f, "metachain"),
+        }
+    }
+}
+
+fn is_smart_contract_on_metachain(public_key: &[u8]) -> bool {
+    if public_key.len() <= NUM_INIT_CHARACTERS_FOR_SC_ADDRESS + NUM_INIT_CHARACTERS_FOR_ON_METACHAIN_SC {
+        return false;
+    }
+
+    let is_smart_contract = public_key[..NUM_ZERO_BYTES_OF_SC_ADDRESS].iter().all(|byte| *byte == 0);
+    if !is_smart_contract {
+        return false;
+    }
+
+    let is_on_metachain_sc_address = public_key[NUM_INIT_CHARACTERS_FOR_SC_ADDRESS..NUM_INIT_CHARACTERS_FOR_SC_ADDRESS + NUM_INIT_CHARACTERS_FOR_ON_METACHAIN_SC]
+        .iter()
+        .all(|byte| *byte == 0);
+
+    is_on_metachain_sc_address || public_key.last() == Some(&u8::MAX)
+}
+
+fn get_masks(num_of_shards: u32) -> (u32, u32) {
+    // The number of bits needed to write the highest shard id.
+    let num_of_bits = u32::BITS - (num_of_shards - 1).leading_zeros();
+
+    let mask_high = (1u32 << num_of_bits) - 1;
+    let mask_low = (1u32 << num_of_bits.saturating_sub(1)) - 1;
+
+    (mask_high, mask_low)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::simulator::shard::ShardId;
+
+    #[test]
+    fn test_shard_of_address() {
+        let alice = "erd1qyu5wthldzr8wx5c9ucg8kjagg0jfs53s8nr3zpz3hypefsdd8ssycr6th";
+        let bob = "erd1spyavw0956vq68xj8y4tenjpq2wd5a9p2c6j8gsz7ztyrnpxrruqzu66jx";
+        let carol = "erd1k2s324ww2g0yj38qn2ch2jwctdy8mnfxep94q9arncc6xecg3xaq6mjse8";
+
+        assert_eq!(ShardId::of_address(alice, 3), Some(ShardId::Shard(1)));
+        assert_eq!(ShardId::of_address(bob, 3), Some(ShardId::Shard(0)));
+        assert_eq!(ShardId::of_address(carol, 3), Some(ShardId::Shard(2)));
+        assert_eq!(ShardId::of_address(carol, 2), Some(ShardId::Shard(0)));
+        assert_eq!(ShardId::of_address(alice, 1), Some(ShardId::Shard(0)));
+        assert_eq!(ShardId::of_address("not an address", 3), None);
+    }
+
+    #[test]
+    fn test_shard_of_metachain_smart_contracts() {
+        let mut system_sc = [1u8; 32];
+        system_sc[..8].copy_from_slice(&[0; 8]);
+        system_sc[10..15].copy_from_slice(&[0; 5]);
+
+        // Not a smart contract, its first bytes are not zero.
+        let mut user_account = [1u8; 32];
+        user_account[10..15].copy_from_slice(&[0; 5]);
+
+        let mut metachain_sc = [1u8; 32];
+        metachain_sc[..8].copy_from_slice(&[0; 8]);
+        metachain_sc[31] = u8::MAX;
+
+        let mut shard_sc = metachain_sc;
+        shard_sc[31] = 2;
+
+        assert_eq!(ShardId::of_public_key(&system_sc, 3), Some(ShardId::Metachain));
+        assert_eq!(ShardId::of_public_key(&user_account, 3), Some(ShardId::Shard(1)));
+        assert_eq!(ShardId::of_public_key(&metachain_sc, 3), Some(ShardId::Metachain));
+        assert_eq!(ShardId::of_public_key(&shard_sc, 3), Some(ShardId::Shard(2)));
+    }
+
+    #[test]
+    fn test_shard_id_conversions() {
+        assert_eq!(ShardId::from(4294967295), ShardId::Metachain);
+        assert_eq!(ShardId::from(2), ShardId::Shard(2));
+        assert_eq!(u32::from(ShardId::Metachain), 4294967295);
+        assert_eq!(ShardId::Metachain.to_string(), "metachain");
+        assert!(ShardId::Shard(2) < ShardId::Metachain);
+    }
+}
@@ -0,0 +1,373 @@
+use std::path::Path;
+use std::str::FromStr;
+
+use num_bigint::BigUint;
+
+use crate::error::lib::LibError;
+use crate::error::staking::StakingError;
+use crate::simulator::batch::PENDING_TRANSACTION_STATUSES;
+use crate::simulator::model::Simulator;
+use crate::simulator::node_config::{NODE_CONFIG_FOLDER, read_config_file_async};
+use crate::simulator::requests::send_transaction::Transaction;
+use crate::simulator::requests::transaction::TransactionOnNetwork;
+use crate::utils::bls::BlsKey;
+
+pub const VALIDATOR_SC_ADDRESS: &str = "erd1qqqqqqqqqqqqqqqpqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqplllst77y4l";
+pub const DELEGATION_MANAGER_SC_ADDRESS: &str = "erd1qqqqqqqqqqqqqqqpqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqylllslmq6y6";
+
+const SYSTEM_SMART_CONTRACTS_CONFIG_NAME: &str = "systemSmartContractsConfig.toml";
+const VALIDATOR_KEY_PATH: &str = "testKeys/validatorKey.pem";
+const EXTRA_VALIDATORS_KEYS_PATH: &str = "testKeys/extraValidatorsKeys.pem";
+
+// The simulator doesn't check the BLS signatures proving the ownership of the staked keys.
+const MOCK_BLS_SIGNATURE: &str = "010101";
+const SC_DEPLOY_EVENT: &str = "SCDeploy";
+const MAX_BLOCKS_TO_EXECUTE_STAKING_TRANSACTION: u64 = 20;
+
+// The gas limits used by the nodes' own chain simulator tests, the system smart contracts' costs can't always be estimated.
+const STAKE_GAS_LIMIT: u64 = 50_000_000;
+const UNSTAKE_GAS_LIMIT: u64 = 50_000_000;
+const UNBOND_GAS_LIMIT: u64 = 12_000_000;
+const CREATE_DELEGATION_CONTRACT_GAS_LIMIT: u64 = 500_000_000;
+const DELEGATE_GAS_LIMIT: u64 = 12_000_000;
+const CLAIM_REWARDS_GAS_LIMIT: u64 = 12_000_000;
+
+#[derive(Clone, PartialEq, Debug)]
+pub struct StakingTransaction {
+    pub tx_hash: String,
+    pub epoch: u64,
+}
+
+#[derive(Clone, PartialEq, Debug)]
+pub struct StakeResult {
+    pub tx_hash: String,
+    pub epoch: u64,
+    pub staked_keys: Vec<String>,
+    pub staked_value: String,
+}
+
+#[derive(Clone, PartialEq, Debug)]
+pub struct DelegationContract {
+    pub tx_hash: String,
+    pub address: String,
+}
+
+#[derive(Clone, PartialEq, Debug)]
+pub struct ClaimedRewards {
+    pub tx_hash: String,
+    pub amount: String,
+}
+
+#[derive(Clone, PartialEq, Debug)]
+struct StakingConfig {
+    node_price: BigUint,
+    unbond_period_in_epochs: u64,
+    delegation_contract_creation_deposit: BigUint,
+}
+
+// The transactions are not signed, so the simulator must be started with `bypass_transactions_signature`.
+impl Simulator {
+    // None of these keys is a genesis validator.
+    pub async fn get_test_validator_keys(&self) -> Result<Vec<BlsKey>, LibError> {
+        let config_folder = self.get_tempdir_path().join(NODE_CONFIG_FOLDER);
+
+        let mut keys = read_validator_keys(&config_folder.join(VALIDATOR_KEY_PATH)).await?;
+        keys.extend(read_validator_keys(&config_folder.join(EXTRA_VALIDATORS_KEYS_PATH)).await?);
+
+        Ok(keys)
+    }
+
+    pub async fn stake(&self, owner: &str, keys: &[BlsKey]) -> Result<StakeResult, LibError> {
+        self.check_transactions_signature_bypassed().await?;
+
+        if keys.is_empty() {
+            return Err(StakingError::NoValidatorKeys.into());
+        }
+
+        let config = self.read_staking_config().await?;
+        let staked_value = config.node_price * keys.len();
+
+        let transaction = Transaction::new(owner, VALIDATOR_SC_ADDRESS)
+            .with_value(staked_value.to_string())
+            .with_data(get_stake_data(keys))
+            .with_gas_limit(STAKE_GAS_LIMIT);
+
+        let executed = self.execute_staking_transaction(&transaction, "stake").await?;
+
+        Ok(StakeResult {
+            tx_hash: executed.hash,
+            epoch: executed.epoch,
+            staked_keys: keys.iter().map(BlsKey::public_key_hex).collect(),
+            staked_value: staked_value.to_string(),
+        })
+    }
+
+    pub async fn unstake(&self, owner: &str, keys: &[BlsKey]) -> Result<StakingTransaction, LibError> {
+        self.check_transactions_signature_bypassed().await?;
+
+        self.call_with_keys(owner, "unStake", keys, UNSTAKE_GAS_LIMIT).await
+    }
+
+    // The unbonding period is generated first, so keys unstaked in the current epoch can be unbonded.
+    pub async fn unbond(&self, owner: &str, keys: &[BlsKey]) -> Result<StakingTransaction, LibError> {
+        self.check_transactions_signature_bypassed().await?;
+
+        let config = self.read_staking_config().await?;
+
+        self.generate_epochs(config.unbond_period_in_epochs).await?;
+
+        self.call_with_keys(owner, "unBond", keys, UNBOND_GAS_LIMIT).await
+    }
+
+    // `service_fee` is in hundredths of a percent, a zero `total_delegation_cap` means no cap.
+    pub async fn create_delegation_contract(&self, owner: &str, total_delegation_cap: &BigUint, service_fee: u64) -> Result<DelegationContract, LibError> {
+        self.check_transactions_signature_bypassed().await?;
+
+        let config = self.read_staking_config().await?;
+
+        let data = format!(
+            "createNewDelegationContract@{}@{}",
+            to_even_hex(total_delegation_cap),
+            to_even_hex(&BigUint::from(service_fee))
+        );
+
+        let transaction = Transaction::new(owner, DELEGATION_MANAGER_SC_ADDRESS)
+            .with_value(config.delegation_contract_creation_deposit.to_string())
+            .with_data(data)
+            .with_gas_limit(CREATE_DELEGATION_CONTRACT_GAS_LIMIT);
+
+        let executed = self.execute_staking_transaction(&transaction, "createNewDelegationContract").await?;
+
+        let Some(address) = get_deployed_contract_address(&executed) else {
+            return Err(StakingError::DelegationContractNotFound { tx_hash: executed.hash }.into());
+        };
+
+        Ok(DelegationContract { tx_hash: executed.hash, address })
+    }
+
+    pub async fn delegate(&self, delegator: &str, delegation_contract: &str, value: &BigUint) -> Result<StakingTransaction, LibError> {
+        self.check_transactions_signature_bypassed().await?;
+
+        let transaction = Transaction::new(delegator, delegation_contract)
+            .with_value(value.to_string())
+            .with_data("delegate")
+            .with_gas_limit(DELEGATE_GAS_LIMIT);
+
+        let executed = self.execute_staking_transaction(&transaction, "delegate").await?;
+
+        Ok(StakingTransaction { tx_hash: executed.hash, epoch: executed.epoch })
+    }
+
+    // Rewards are only distributed at the end of an epoch, so one is generated first.
+    pub async fn claim_rewards(&self, delegator: &str, delegation_contract: &str) -> Result<ClaimedRewards, LibError> {
+        self.check_transactions_signature_bypassed().await?;
+
+        self.generate_epochs(1).await?;
+
+        let transaction = Transaction::new(delegator, delegation_contract)
+            .with_data("claimRewards")
+            .with_gas_limit(CLAIM_REWARDS_GAS_LIMIT);
+
+        let executed = self.execute_staking_transaction(&transaction, "claimRewards").await?;
+        let amount = get_claimed_rewards(&executed, delegator, delegation_contract);
+
+        Ok(ClaimedRewards { tx_hash: executed.hash, amount: amount.to_string() })
+    }
+
+    async fn check_transactions_signature_bypassed(&self) -> Result<(), LibError> {
+        let (_, options) = self.get_process_id_and_options().await?;
+
+        if !options.bypass_txs_signature {
+            return Err(StakingError::TransactionsSignatureNotBypassed.into());
+        }
+
+        Ok(())
+    }
+
+    async fn call_with_keys(&self, owner: &str, function: &str, keys: &[BlsKey], gas_limit: u64) -> Result<StakingTransaction, LibError> {
+        if keys.is_empty() {
+            return Err(StakingError::NoValidatorKeys.into());
+        }
+
+        let data = keys.iter().fold(function.to_string(), |data, key| format!("{data}@{}", key.public_key_hex()));
+
+        let transaction = Transaction::new(owner, VALIDATOR_SC_ADDRESS)
+            .with_data(data)
+            .with_gas_limit(gas_limit);
+
+        let executed = self.execute_staking_transaction(&transaction, function).await?;
+
+        Ok(StakingTransaction { tx_hash: executed.hash, epoch: executed.epoch })
+    }
+
+    // Blocks are generated one at a time until the transaction and its cross-shard results are processed.
+    async fn execute_staking_transaction(&self, transaction: &Transaction, function: &str) -> Result<TransactionOnNetwork, LibError> {
+        let tx_hash = self.send_transaction(transaction).await?;
+
+        for _ in 0..MAX_BLOCKS_TO_EXECUTE_STAKING_TRANSACTION {
+            self.generate_blocks(1).await?;
+
+            let executed = self.get_transaction(&tx_hash).await?;
+
+            if PENDING_TRANSACTION_STATUSES.contains(&executed.status.as_str()) {
+                continue;
+            }
+
+            if !executed.is_success() {
+                return Err(StakingError::TransactionFailed { tx_hash, function: function.to_string(), message: executed.error_message() }.into());
+            }
+
+            return Ok(executed);
+        }
+
+        Err(StakingError::TransactionNotExecuted { tx_hash, max_blocks: MAX_BLOCKS_TO_EXECUTE_STAKING_TRANSACTION }.into())
+    }
+
+    async fn read_staking_config(&self) -> Result<StakingConfig, LibError> {
+        let file_path = self.get_tempdir_path().join(NODE_CONFIG_FOLDER).join(SYSTEM_SMART_CONTRACTS_CONFIG_NAME);
+        let config = read_config_file_async(&file_path).await?;
+
+        let get_value = |section: &str, key: &str| {
+            let value = config.get(section).and_then(|section| section.get(key));
+
+            let Some(value) = value else {
+                return Err(LibError::from(StakingError::CannotReadStakingConfig {
+                    file_path: file_path.to_string_lossy().to_string(),
+                    key: format!("{section}.{key}"),
+                }));
+            };
+
+            Ok(value)
+        };
+
+        let parse_error = |key: &str| LibError::from(StakingError::CannotReadStakingConfig {
+            file_path: file_path.to_string_lossy().to_string(),
+            key: key.to_string(),
+        });
+
+        let node_price = get_value("StakingSystemSCConfig", "GenesisNodePrice")?
+            .as_str()
+            .and_then(|value| BigUint::from_str(value).ok())
+            .ok_or_else(|| parse_error("StakingSystemSCConfig.GenesisNodePrice"))?;
+
+        let unbond_period_in_epochs = get_value("StakingSystemSCConfig", "UnBondPeriodInEpochs")?
+            .as_integer()
+            .and_then(|value| u64::try_from(value).ok())
+            .ok_or_else(|| parse_error("StakingSystemSCConfig.UnBondPeriodInEpochs"))?;
+
+        let delegation_contract_creation_deposit = get_value("DelegationManagerSystemSCConfig", "MinCreationDeposit")?
+            .as_str()
+            .and_then(|value| BigUint::from_str(value).ok())
+            .ok_or_else(|| parse_error("DelegationManagerSystemSCConfig.MinCreationDeposit"))?;
+
+        Ok(StakingConfig { node_price, unbond_period_in_epochs, delegation_contract_creation_deposit })
+    }
+}
+
+async fn read_validator_keys(file_path: &Path) -> Result<Vec<BlsKey>, LibError> {
+    let keys = tokio::fs::read_to_string(file_path)
+        .await
+        .ok()
+        .and_then(|content| BlsKey::parse_pem(&content));
+
+    let Some(keys) = keys else {
+        return Err(StakingError::CannotReadValidatorKeys { file_path: file_path.to_string_lossy().to_string() }.into());
+    };
+
+    Ok(keys)
+}
+
+fn get_stake_data(keys: &[BlsKey]) -> String {
+    keys.iter().fold(
+        format!("stake@{}", to_even_hex(&BigUint::from(keys.len()))),
+        |data, key| format!("{data}@{}@{MOCK_BLS_SIGNATURE}", key.public_key_hex()),
+    )
+}
+
+fn get_deployed_contract_address(transaction: &TransactionOnNetwork) -> Option<String> {
+    transaction
+        .find_events(SC_DEPLOY_EVENT)
+        .first()
+        .map(|event| event.address.clone())
+}
+
+// The rewards are transferred by a result without data, unlike the one returning the call's output along with the refunded gas.
+fn get_claimed_rewards(transaction: &TransactionOnNetwork, delegator: &str, delegation_contract: &str) -> BigUint {
+    transaction
+        .smart_contract_results
+        .iter()
+        .filter(|scr| scr.sender == delegation_contract && scr.receiver == delegator && !scr.data.starts_with('@'))
+        .filter_map(|scr| BigUint::from_str(&scr.value).ok())
+        .sum()
+}
+
+// The arguments of a call are hex encoded with an even number of digits, zero being an empty argument.
+fn to_even_hex(value: &BigUint) -> String {
+    if *value == BigUint::ZERO {
+        return String::new();
+    }
+
+    let hex = value.to_str_radix(16);
+
+    if hex.len() % 2 == 1 {
+        format!("0{hex}")
+    } else {
+        hex
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use num_bigint::BigUint;
+
+    use crate::simulator::requests::transaction::TransactionOnNetwork;
+    use crate::simulator::staking::{DELEGATION_MANAGER_SC_ADDRESS, get_claimed_rewards, get_deployed_contract_address, get_stake_data, to_even_hex, VALIDATOR_SC_ADDRESS};
+    use crate::utils::address::bech32_to_public_key;
+    use crate::utils::bls::BlsKey;
+
+    #[test]
+    fn test_system_smart_contracts_addresses() {
+        let mut validator = [0u8; 32];
+        validator[9] = 1;
+        validator[29..].copy_from_slice(&[1, 0xff, 0xff]);
+
+        let mut delegation_manager = validator;
+        delegation_manager[29] = 4;
+
+        assert_eq!(bech32_to_public_key(VALIDATOR_SC_ADDRESS), Some(validator.to_vec()));
+        assert_eq!(bech32_to_public_key(DELEGATION_MANAGER_SC_ADDRESS), Some(delegation_manager.to_vec()));
+    }
+
+    #[test]
+    fn test_stake_data() {
+        let keys = [BlsKey::generate(), BlsKey::generate()];
+
+        let expected = format!("stake@02@{}@010101@{}@010101", keys[0].public_key_hex(), keys[1].public_key_hex());
+
+        assert_eq!(get_stake_data(&keys), expected);
+        assert_eq!(to_even_hex(&BigUint::from(4096u32)), "1000");
+        assert_eq!(to_even_hex(&BigUint::from(0u32)), "");
+    }
+
+    #[test]
+    fn test_delegation_results_are_parsed() {
+        let delegator = "erd1qyu5wthldzr8wx5c9ucg8kjagg0jfs53s8nr3zpz3hypefsdd8ssycr6th";
+        let contract = "erd1qqqqqqqqqqqqqqqpqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqzllls8a5w6u";
+        let transaction: TransactionOnNetwork = serde_json::from_value(serde_json::json!({
+            "type": "normal",
+            "status": "success",
+            "smartContractResults": [
+                { "sender": contract, "receiver": delegator, "value": 1500, "data": "" },
+                { "sender": contract, "receiver": delegator, "value": 300, "data": "@6f6b" },
+                { "sender": delegator, "receiver": contract, "value": 1000, "data": "" },
+            ],
+            "logs": {
+                "address": contract,
+                "events": [{ "address": contract, "identifier": "SCDeploy", "topics": [] }],
+            },
+        })).unwrap();
+
+        assert_eq!(get_claimed_rewards(&transaction, delegator, contract), BigUint::from(1500u32));
+        assert_eq!(get_deployed_contract_address(&transaction).as_deref(), Some(contract));
+    }
+}
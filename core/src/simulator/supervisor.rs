@@ -0,0 +1,145 @@
+use std::collections::VecDeque;
+use std::io::{BufRead, BufReader};
+use std::os::unix::process::ExitStatusExt;
+use std::process::Child;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Mutex};
+use std::thread;
+
+use tokio::sync::{broadcast, watch};
+
+use crate::error::lib::LibError;
+
+const LOG_TAIL_CAPACITY: usize = 100;
+
+#[derive(Clone, PartialEq, Debug)]
+pub struct SimulatorExit {
+    pub code: Option<i32>,
+    pub signal: Option<i32>,
+    pub last_log_lines: Vec<String>,
+}
+
+#[derive(Clone, PartialEq, Debug)]
+pub enum SimulatorEvent {
+    LogLine(String),
+    Exited(SimulatorExit),
+    Restarted,
+    RestartFailed(LibError),
+    AutogenerationFailed(LibError),
+}
+
+// Waits on the child from a dedicated thread rather than probing its pid.
+pub(crate) struct SimulatorSupervisor {
+    exit: watch::Sender<Option<SimulatorExit>>,
+    is_stopping: AtomicBool,
+}
+
+impl SimulatorSupervisor {
+    pub(crate) fn start(mut child: Child, events: broadcast::Sender<SimulatorEvent>) -> Arc<SimulatorSupervisor> {
+        let supervisor = Arc::new(SimulatorSupervisor {
+            exit: watch::Sender::new(None),
+            is_stopping: AtomicBool::new(false),
+        });
+
+        let log_tail = Arc::new(Mutex::new(VecDeque::with_capacity(LOG_TAIL_CAPACITY)));
+
+        let reader_thread = child.stdout.take().map(|stdout| {
+            let log_tail = log_tail.clone();
+            let events = events.clone();
+
+            thread::spawn(move || {
+                let reader = BufReader::new(stdout);
+                for line in reader.lines().map_while(Result::ok) {
+                    {
+                        let mut log_tail = log_tail.lock().unwrap();
+                        if log_tail.len() == LOG_TAIL_CAPACITY {
+                            log_tail.pop_front();
+                        }
+                        log_tail.push_back(line.clone());
+                    }
+
+                    let _ = events.send(SimulatorEvent::LogLine(line)); // There might be no subscriber.
+                }
+            })
+        });
+
+        let waiter_supervisor = supervisor.clone();
+        thread::spawn(move || {
+            let exit_status = child.wait();
+
+            // The whole output has to be read before recording the exit, otherwise the last lines would be missing.
+            if let Some(reader_thread) = reader_thread {
+                let _ = reader_thread.join();
+            }
+
+            let last_log_lines = log_tail.lock().unwrap().iter().cloned().collect();
+            let exit = SimulatorExit {
+                code: exit_status.as_ref().ok().and_then(|status| status.code()),
+                signal: exit_status.as_ref().ok().and_then(|status| status.signal()),
+                last_log_lines,
+            };
+
+            waiter_supervisor.exit.send_replace(Some(exit.clone()));
+            let _ = events.send(SimulatorEvent::Exited(exit));
+        });
+
+        supervisor
+    }
+
+    pub(crate) fn get_exit(&self) -> Option<SimulatorExit> {
+        self.exit.borrow().clone()
+    }
+
+    pub(crate) async fn wait_for_exit(&self) -> SimulatorExit {
+        let mut receiver = self.exit.subscribe();
+
+        let exit = receiver
+            .wait_for(|exit| exit.is_some())
+            .await
+            .expect("the sender lives as long as the supervisor");
+
+        exit.clone().unwrap()
+    }
+
+    pub(crate) fn mark_as_stopping(&self) {
+        self.is_stopping.store(true, Ordering::SeqCst);
+    }
+
+    pub(crate) fn is_stopping(&self) -> bool {
+        self.is_stopping.load(Ordering::SeqCst)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::process::{Command, Stdio};
+
+    use tokio::sync::broadcast;
+
+    use crate::simulator::supervisor::{SimulatorEvent, SimulatorExit, SimulatorSupervisor};
+
+    #[tokio::test]
+    async fn test_supervisor_records_exit_and_notifies() {
+        let (events, mut receiver) = broadcast::channel(16);
+        let child = Command::new("sh")
+            .args(["-c", "echo first; echo second; exit 2"])
+            .stdout(Stdio::piped())
+            .spawn()
+            .unwrap();
+
+        let supervisor = SimulatorSupervisor::start(child, events);
+        let exit = supervisor.wait_for_exit().await;
+
+        let expected = SimulatorExit {
+            code: Some(2),
+            signal: None,
+            last_log_lines: vec!["first".to_string(), "second".to_string()],
+        };
+
+        assert_eq!(exit, expected);
+        assert_eq!(supervisor.get_exit(), Some(expected.clone()));
+        assert_eq!(receiver.recv().await.unwrap(), SimulatorEvent::LogLine("first".to_string()));
+        assert_eq!(receiver.recv().await.unwrap(), SimulatorEvent::LogLine("second".to_string()));
+        assert_eq!(receiver.recv().await.unwrap(), SimulatorEvent::Exited(expected));
+    }
+}
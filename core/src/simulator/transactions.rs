@@ -0,0 +1,369 @@
+use base64::Engine;
+use base64::engine::general_purpose::STANDARD as BASE64;
+use reqwest::Client;
+
+use crate::error::lib::LibError;
+use crate::error::requests::address_nonce::AddressNonceError;
+use crate::error::requests::send_transaction::SendTransactionError;
+use crate::error::requests::simulate_transaction::SimulateTransactionError;
+use crate::error::requests::transaction_cost::TransactionCostError;
+use crate::error::requests::transaction_pool::TransactionPoolError;
+use crate::simulator::model::Simulator;
+use crate::simulator::requests::network_config::NetworkConfig;
+use crate::simulator::requests::address_nonce::AddressNonceResponse;
+use crate::simulator::requests::send_transaction::{SendTransactionResponse, Transaction, TransactionRequest};
+use crate::simulator::requests::simulate_transaction::{TransactionSimulation, TransactionSimulationResponse};
+use crate::simulator::requests::transaction_cost::{TransactionCost, TransactionCostResponse};
+use crate::simulator::requests::transaction_pool::TransactionPoolResponse;
+
+// Only accepted by a simulator bypassing the signatures check.
+const EMPTY_SIGNATURE: &str = "00000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000";
+
+impl Simulator {
+    // Without a nonce, the transaction is sent again once if the managed nonce turns out to be out of sync.
+    pub async fn send_transaction(&self, transaction: &Transaction) -> Result<String, LibError> {
+        let (_, options) = self.get_process_id_and_options().await?;
+
+        match self.send_transaction_with_managed_nonce(options.server_port, transaction).await {
+            Err(error) if transaction.nonce.is_none() && is_nonce_error(&error) => {
+                self.send_transaction_with_managed_nonce(options.server_port, transaction).await
+            },
+            result => result,
+        }
+    }
+
+    pub async fn estimate_transaction_cost(&self, transaction: &Transaction) -> Result<TransactionCost, LibError> {
+        let (_, options) = self.get_process_id_and_options().await?;
+
+        // A gas limit is set, otherwise preparing the transaction would estimate its cost too.
+        let transaction = self.with_peeked_nonce(options.server_port, transaction).await?
+            .with_gas_limit(transaction.gas_limit.unwrap_or_default());
+        let config = self.get_cached_network_config(options.server_port).await?;
+        let request = prepare_transaction(options.server_port, config, &transaction).await?;
+
+        estimate_transaction_cost(options.server_port, &request).await
+    }
+
+    pub async fn simulate_transaction(&self, transaction: &Transaction) -> Result<TransactionSimulation, LibError> {
+        let (_, options) = self.get_process_id_and_options().await?;
+
+        let transaction = self.with_peeked_nonce(options.server_port, transaction).await?;
+        let config = self.get_cached_network_config(options.server_port).await?;
+        let request = prepare_transaction(options.server_port, config, &transaction).await?;
+
+        let url = format!("http://localhost:{}/transaction/simulate?checkSignature=false", options.server_port);
+
+        let Ok(body) = serde_json::to_string(&request) else {
+            return Err(SimulateTransactionError::CannotConvertTransactionAsJSON { url }.into())
+        };
+
+        let Ok(response) = Client::new()
+            .post(&url)
+            .body(body)
+            .send()
+            .await
+            else {
+                return Err(SimulateTransactionError::CannotSendRequest { url }.into());
+            };
+
+        if !response.status().is_success() {
+            return Err(SimulateTransactionError::ResponseStatusIsNotSuccessful { url, status: response.status().as_u16() }.into());
+        }
+
+        let Ok(text) = response.text().await else {
+            return Err(SimulateTransactionError::CannotGetTextFromTheResponse { url }.into());
+        };
+
+        let Ok(result) = serde_json::from_str::<TransactionSimulationResponse>(&text) else {
+            return Err(SimulateTransactionError::FailedToParseTheResponse { url, response: text }.into());
+        };
+
+        if result.code != "successful" {
+            return Err(SimulateTransactionError::ResponseCodeIsNotSuccessful { url, code: result.code }.into());
+        }
+
+        let Some(data) = result.data else {
+            return Err(SimulateTransactionError::ResponseCodeIsNotSuccessful { url, code: result.code }.into());
+        };
+
+        Ok(data.into())
+    }
+
+    // On a nonce mismatch, the sender's nonce is reloaded on its next transaction.
+    async fn send_transaction_with_managed_nonce(&self, server_port: u16, transaction: &Transaction) -> Result<String, LibError> {
+        // A nonce reserved for a transaction that is never sent would leave a gap.
+        let request = match self.prepare_transaction_with_managed_nonce(server_port, transaction).await {
+            Ok(request) => request,
+            Err(error) => {
+                self.get_nonce_manager().invalidate(&transaction.sender).await;
+                return Err(error);
+            }
+        };
+
+        let result = send_transaction(server_port, &request).await;
+
+        if let Err(error) = &result {
+            if is_nonce_error(error) {
+                self.get_nonce_manager().invalidate(&transaction.sender).await;
+            }
+        }
+
+        result
+    }
+
+    pub(crate) async fn prepare_transaction_with_managed_nonce(&self, server_port: u16, transaction: &Transaction) -> Result<TransactionRequest, LibError> {
+        let nonce = match transaction.nonce {
+            Some(nonce) => nonce,
+            None => {
+                self.get_nonce_manager()
+                    .next_nonce(&transaction.sender, || get_pool_aware_nonce(server_port, &transaction.sender))
+                    .await?
+            }
+        };
+
+        let config = self.get_cached_network_config(server_port).await?;
+
+        prepare_transaction(server_port, config, &transaction.clone().with_nonce(nonce)).await
+    }
+
+    async fn with_peeked_nonce(&self, server_port: u16, transaction: &Transaction) -> Result<Transaction, LibError> {
+        let nonce = match transaction.nonce {
+            Some(nonce) => nonce,
+            None => {
+                self.get_nonce_manager()
+                    .peek_nonce(&transaction.sender, || get_pool_aware_nonce(server_port, &transaction.sender))
+                    .await?
+            }
+        };
+
+        Ok(transaction.clone().with_nonce(nonce))
+    }
+}
+
+// The gas limit is estimated last since it depends on all the other fields.
+pub(crate) async fn prepare_transaction(server_port: u16, config: NetworkConfig, transaction: &Transaction) -> Result<TransactionRequest, LibError> {
+    let nonce = match transaction.nonce {
+        Some(nonce) => nonce,
+        None => get_address_nonce(server_port, &transaction.sender).await?,
+    };
+
+    let mut request = TransactionRequest {
+        nonce,
+        value: transaction.value.clone(),
+        receiver: transaction.receiver.clone(),
+        sender: transaction.sender.clone(),
+        gas_price: transaction.gas_price.unwrap_or(config.min_gas_price),
+        gas_limit: transaction.gas_limit.unwrap_or_default(),
+        data: transaction.data.as_ref().map(|data| BASE64.encode(data)),
+        signature: transaction.signature.clone().unwrap_or_else(|| EMPTY_SIGNATURE.to_string()),
+        chain_id: transaction.chain_id.clone().unwrap_or(config.chain_id),
+        version: transaction.version.unwrap_or(config.min_transaction_version),
+        options: transaction.options,
+    };
+
+    if transaction.gas_limit.is_none() {
+        let url = format!("http://localhost:{}/transaction/cost", server_port);
+        let cost = estimate_transaction_cost(server_port, &request).await?;
+
+        if !cost.return_message.is_empty() {
+            return Err(TransactionCostError::EstimationFailed { url, return_message: cost.return_message }.into());
+        }
+
+        request.gas_limit = cost.tx_gas_units;
+    }
+
+    Ok(request)
+}
+
+pub(crate) async fn send_transaction(server_port: u16, request: &TransactionRequest) -> Result<String, LibError> {
+    let url = format!("http://localhost:{}/transaction/send", server_port);
+
+    let Ok(body) = serde_json::to_string(request) else {
+        return Err(SendTransactionError::CannotConvertTransactionAsJSON { url }.into())
+    };
+
+    let Ok(response) = Client::new()
+        .post(&url)
+        .body(body)
+        .send()
+        .await
+        else {
+            return Err(SendTransactionError::CannotSendRequest { url }.into());
+        };
+
+    let status = response.status();
+
+    let Ok(text) = response.text().await else {
+        return Err(SendTransactionError::CannotGetTextFromTheResponse { url }.into());
+    };
+
+    if !status.is_success() {
+        // The reason of a rejection, such as a nonce mismatch, is only given in the response's body.
+        return match serde_json::from_str::<SendTransactionResponse>(&text) {
+            Ok(result) if !result.error.is_empty() => Err(SendTransactionError::TransactionRejected { url, error: result.error }.into()),
+            _ => Err(SendTransactionError::ResponseStatusIsNotSuccessful { url, status: status.as_u16() }.into()),
+        };
+    }
+
+    let Ok(result) = serde_json::from_str::<SendTransactionResponse>(&text) else {
+        return Err(SendTransactionError::FailedToParseTheResponse { url, response: text }.into());
+    };
+
+    if result.code != "successful" {
+        return Err(SendTransactionError::ResponseCodeIsNotSuccessful { url, code: result.code }.into());
+    }
+
+    let Some(data) = result.data else {
+        return Err(SendTransactionError::ResponseCodeIsNotSuccessful { url, code: result.code }.into());
+    };
+
+    Ok(data.tx_hash)
+}
+
+fn is_nonce_error(error: &LibError) -> bool {
+    let LibError::SendTransaction(SendTransactionError::TransactionRejected { error, .. }) = error else {
+        return false;
+    };
+
+    ["lowerNonceInTx: true", "veryHighNonceInTx: true", "nonce too low", "nonce too high"]
+        .iter()
+        .any(|message| error.contains(message))
+}
+
+async fn estimate_transaction_cost(server_port: u16, request: &TransactionRequest) -> Result<TransactionCost, LibError> {
+    let url = format!("http://localhost:{}/transaction/cost", server_port);
+
+    let Ok(body) = serde_json::to_string(request) else {
+        return Err(TransactionCostError::CannotConvertTransactionAsJSON { url }.into())
+    };
+
+    let Ok(response) = Client::new()
+        .post(&url)
+        .body(body)
+        .send()
+        .await
+        else {
+            return Err(TransactionCostError::CannotSendRequest { url }.into());
+        };
+
+    if !response.status().is_success() {
+        return Err(TransactionCostError::ResponseStatusIsNotSuccessful { url, status: response.status().as_u16() }.into());
+    }
+
+    let Ok(text) = response.text().await else {
+        return Err(TransactionCostError::CannotGetTextFromTheResponse { url }.into());
+    };
+
+    let Ok(result) = serde_json::from_str::<TransactionCostResponse>(&text) else {
+        return Err(TransactionCostError::FailedToParseTheResponse { url, response: text }.into());
+    };
+
+    if result.code != "successful" {
+        return Err(TransactionCostError::ResponseCodeIsNotSuccessful { url, code: result.code }.into());
+    }
+
+    let Some(data) = result.data else {
+        return Err(TransactionCostError::ResponseCodeIsNotSuccessful { url, code: result.code }.into());
+    };
+
+    Ok(data)
+}
+
+pub(crate) async fn get_address_nonce(server_port: u16, address: &str) -> Result<u64, LibError> {
+    let url = format!("http://localhost:{}/address/{}/nonce", server_port, address);
+
+    let Ok(response) = Client::new()
+        .get(&url)
+        .send()
+        .await
+        else {
+            return Err(AddressNonceError::CannotSendRequest { url }.into());
+        };
+
+    if !response.status().is_success() {
+        return Err(AddressNonceError::ResponseStatusIsNotSuccessful { url, status: response.status().as_u16() }.into());
+    }
+
+    let Ok(text) = response.text().await else {
+        return Err(AddressNonceError::CannotGetTextFromTheResponse { url }.into());
+    };
+
+    let Ok(result) = serde_json::from_str::<AddressNonceResponse>(&text) else {
+        return Err(AddressNonceError::FailedToParseTheResponse { url, response: text }.into());
+    };
+
+    if result.code != "successful" {
+        return Err(AddressNonceError::ResponseCodeIsNotSuccessful { url, code: result.code }.into());
+    }
+
+    let Some(data) = result.data else {
+        return Err(AddressNonceError::ResponseCodeIsNotSuccessful { url, code: result.code }.into());
+    };
+
+    Ok(data.nonce)
+}
+
+// The sender's transactions still in the pool already hold the nonces following the account's one.
+async fn get_pool_aware_nonce(server_port: u16, address: &str) -> Result<u64, LibError> {
+    let account_nonce = get_address_nonce(server_port, address).await?;
+    let pool_nonces = get_pool_transactions_nonces(server_port, address).await?;
+
+    Ok(next_nonce_after_pool(account_nonce, &pool_nonces))
+}
+
+fn next_nonce_after_pool(account_nonce: u64, pool_nonces: &[u64]) -> u64 {
+    pool_nonces.iter()
+        .map(|nonce| nonce + 1)
+        .fold(account_nonce, u64::max)
+}
+
+async fn get_pool_transactions_nonces(server_port: u16, address: &str) -> Result<Vec<u64>, LibError> {
+    let url = format!("http://localhost:{}/transaction/pool?by-sender={}&fields=nonce", server_port, address);
+
+    let Ok(response) = Client::new()
+        .get(&url)
+        .send()
+        .await
+        else {
+            return Err(TransactionPoolError::CannotSendRequest { url }.into());
+        };
+
+    if !response.status().is_success() {
+        return Err(TransactionPoolError::ResponseStatusIsNotSuccessful { url, status: response.status().as_u16() }.into());
+    }
+
+    let Ok(text) = response.text().await else {
+        return Err(TransactionPoolError::CannotGetTextFromTheResponse { url }.into());
+    };
+
+    let Ok(result) = serde_json::from_str::<TransactionPoolResponse>(&text) else {
+        return Err(TransactionPoolError::FailedToParseTheResponse { url, response: text }.into());
+    };
+
+    if result.code != "successful" {
+        return Err(TransactionPoolError::ResponseCodeIsNotSuccessful { url, code: result.code }.into());
+    }
+
+    let Some(data) = result.data else {
+        return Err(TransactionPoolError::ResponseCodeIsNotSuccessful { url, code: result.code }.into());
+    };
+
+    Ok(data.tx_pool.transactions.iter().map(|transaction| transaction.tx_fields.nonce).collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::simulator::requests::transaction_pool::TransactionPoolResponse;
+    use crate::simulator::transactions::next_nonce_after_pool;
+
+    #[test]
+    fn test_next_nonce_follows_the_pool_transactions() {
+        let response = r#"{"data":{"txPool":{"transactions":[{"txFields":{"nonce":7}},{"txFields":{"nonce":8}}]}},"error":"","code":"successful"}"#;
+        let response: TransactionPoolResponse = serde_json::from_str(response).unwrap();
+        let pool_nonces: Vec<u64> = response.data.unwrap().tx_pool.transactions.iter().map(|transaction| transaction.tx_fields.nonce).collect();
+
+        assert_eq!(next_nonce_after_pool(7, &pool_nonces), 9);
+        assert_eq!(next_nonce_after_pool(7, &[]), 7);
+        assert_eq!(next_nonce_after_pool(10, &pool_nonces), 10);
+    }
+}
@@ -0,0 +1,182 @@
+use std::collections::HashMap;
+
+use base64::Engine;
+use base64::engine::general_purpose::STANDARD as BASE64;
+use reqwest::Client;
+
+use crate::error::lib::LibError;
+use crate::error::requests::add_validator_keys::AddValidatorKeysError;
+use crate::error::requests::validator_statistics::ValidatorStatisticsError;
+use crate::simulator::model::Simulator;
+use crate::simulator::requests::add_validator_keys::{AddValidatorKeysRequest, AddValidatorKeysResponse};
+use crate::simulator::requests::validator_statistics::{ValidatorStatistics, ValidatorStatisticsResponse};
+use crate::utils::bls::BlsKey;
+
+impl Simulator {
+    pub async fn add_validator_keys(&self, keys: &[BlsKey]) -> Result<(), LibError> {
+        if keys.is_empty() {
+            return Err(AddValidatorKeysError::NoKeys.into());
+        }
+
+        let (_, options) = self.get_process_id_and_options().await?;
+
+        let url = format!("http://localhost:{}/simulator/add-keys", options.server_port);
+
+        let request = create_add_validator_keys_request(keys);
+
+        let Ok(body) = serde_json::to_string(&request) else {
+            return Err(AddValidatorKeysError::CannotConvertKeysAsJSON { url }.into())
+        };
+
+        let Ok(response) = Client::new()
+            .post(&url)
+            .body(body)
+            .send()
+            .await
+            else {
+                return Err(AddValidatorKeysError::CannotSendRequest { url }.into());
+            };
+
+        let status = response.status();
+
+        let Ok(text) = response.text().await else {
+            return Err(AddValidatorKeysError::CannotGetTextFromTheResponse { url }.into());
+        };
+
+        if !status.is_success() {
+            // The reason of a rejection, such as an already registered key, is only given in the response's body.
+            return match serde_json::from_str::<AddValidatorKeysResponse>(&text) {
+                Ok(result) if !result.error.is_empty() => Err(AddValidatorKeysError::KeysRejected { url, error: result.error }.into()),
+                _ => Err(AddValidatorKeysError::ResponseStatusIsNotSuccessful { url, status: status.as_u16() }.into()),
+            };
+        }
+
+        let Ok(result) = serde_json::from_str::<AddValidatorKeysResponse>(&text) else {
+            return Err(AddValidatorKeysError::FailedToParseTheResponse { url, response: text }.into());
+        };
+
+        if result.code != "successful" {
+            return Err(AddValidatorKeysError::ResponseCodeIsNotSuccessful { url, code: result.code }.into());
+        }
+
+        Ok(())
+    }
+
+    pub async fn add_validator_keys_from_pem(&self, pem: &str) -> Result<Vec<BlsKey>, LibError> {
+        let Some(keys) = BlsKey::parse_pem(pem) else {
+            return Err(AddValidatorKeysError::InvalidPem.into());
+        };
+
+        self.add_validator_keys(&keys).await?;
+
+        Ok(keys)
+    }
+
+    // A staked key is `eligible` once selected at an epoch start.
+    pub async fn get_validator_statistics(&self) -> Result<HashMap<String, ValidatorStatistics>, LibError> {
+        let (_, options) = self.get_process_id_and_options().await?;
+
+        let url = format!("http://localhost:{}/validator/statistics", options.server_port);
+
+        let Ok(response) = Client::new()
+            .get(&url)
+            .send()
+            .await
+            else {
+                return Err(ValidatorStatisticsError::CannotSendRequest { url }.into());
+            };
+
+        if !response.status().is_success() {
+            return Err(ValidatorStatisticsError::ResponseStatusIsNotSuccessful { url, status: response.status().as_u16() }.into());
+        }
+
+        let Ok(text) = response.text().await else {
+            return Err(ValidatorStatisticsError::CannotGetTextFromTheResponse { url }.into());
+        };
+
+        let Ok(result) = serde_json::from_str::<ValidatorStatisticsResponse>(&text) else {
+            return Err(ValidatorStatisticsError::FailedToParseTheResponse { url, response: text }.into());
+        };
+
+        if result.code != "successful" {
+            return Err(ValidatorStatisticsError::ResponseCodeIsNotSuccessful { url, code: result.code }.into());
+        }
+
+        let Some(data) = result.data else {
+            return Err(ValidatorStatisticsError::MissingData { url }.into());
+        };
+
+        Ok(data.statistics)
+    }
+}
+
+fn create_add_validator_keys_request(keys: &[BlsKey]) -> AddValidatorKeysRequest {
+    AddValidatorKeysRequest {
+        private_keys_base64: keys.iter().map(|key| BASE64.encode(hex::encode(key.secret_key()))).collect(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+    use std::path::Path;
+
+    use crate::simulator::requests::validator_statistics::ValidatorStatisticsResponse;
+    use crate::simulator::validators::create_add_validator_keys_request;
+    use crate::utils::bls::BlsKey;
+
+    #[test]
+    fn test_add_validator_keys_request_holds_the_pem_bodies() {
+        let pem_path = Path::new(env!("CARGO_MANIFEST_DIR")).join("../config/assets/config/node/config/testKeys/validatorKey.pem");
+        let keys = BlsKey::parse_pem(&fs::read_to_string(pem_path).unwrap()).unwrap();
+
+        let request = serde_json::to_string(&create_add_validator_keys_request(&keys)).unwrap();
+
+        assert_eq!(
+            request,
+            r#"{"privateKeysBase64":["MmVkOGZmZDRmNWQ5NjIyMjU5YjRiYjE2OGQ5ZTk2YjYxMjIyMmMwOGU5NTM4MTczMGVkMzI3ODY4Y2I2NDUwNA=="]}"#
+        );
+    }
+
+    #[test]
+    fn test_parse_validator_statistics() {
+        let response = r#"{
+            "data": {
+                "statistics": {
+                    "75907bf6": {
+                        "tempRating": 50.5,
+                        "numLeaderSuccess": 2,
+                        "numLeaderFailure": 0,
+                        "numValidatorSuccess": 10,
+                        "numValidatorFailure": 1,
+                        "numValidatorIgnoredSignatures": 0,
+                        "rating": 50,
+                        "ratingModifier": 1,
+                        "totalNumLeaderSuccess": 4,
+                        "totalNumLeaderFailure": 0,
+                        "totalNumValidatorSuccess": 20,
+                        "totalNumValidatorFailure": 1,
+                        "totalNumValidatorIgnoredSignatures": 0,
+                        "shardId": 4294967295,
+                        "validatorStatus": "eligible"
+                    },
+                    "3ed3cd69": {
+                        "shardId": 1,
+                        "validatorStatus": "waiting"
+                    }
+                }
+            },
+            "error": "",
+            "code": "successful"
+        }"#;
+
+        let statistics = serde_json::from_str::<ValidatorStatisticsResponse>(response).unwrap().data.unwrap().statistics;
+
+        let eligible = &statistics["75907bf6"];
+        assert!(eligible.is_eligible());
+        assert_eq!(eligible.shard_id, 4294967295);
+        assert_eq!(eligible.rating, 50.0);
+        assert_eq!(eligible.total_num_validator_success, 20);
+        assert!(!statistics["3ed3cd69"].is_eligible());
+    }
+}
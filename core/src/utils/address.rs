@@ -0,0 +1,35 @@
+use bech32::{Bech32, Hrp};
+
+const ADDRESS_HRP: &str = "erd";
+
+pub(crate) fn bech32_to_public_key(address: &str) -> Option<Vec<u8>> {
+    let (hrp, public_key) = bech32::decode(address).ok()?;
+
+    if hrp.as_str() != ADDRESS_HRP {
+        return None;
+    }
+
+    Some(public_key)
+}
+
+pub(crate) fn public_key_to_bech32(public_key: &[u8]) -> Option<String> {
+    let hrp = Hrp::parse(ADDRESS_HRP).ok()?;
+
+    bech32::encode::<Bech32>(hrp, public_key).ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::utils::address::{bech32_to_public_key, public_key_to_bech32};
+
+    #[test]
+    fn test_bech32_round_trip() {
+        let address = "erd1qqqqqqqqqqqqqpgqp699jngundfqw07d8jzkepucvpzush6k3wvqyc44rx";
+
+        let public_key = bech32_to_public_key(address).unwrap();
+
+        assert_eq!(public_key.len(), 32);
+        assert_eq!(public_key_to_bech32(&public_key).as_deref(), Some(address));
+        assert_eq!(bech32_to_public_key("bc1qar0srrr7xfkvy5l643lydnw9re59gtzzwf5mdq"), None);
+    }
+}
@@ -0,0 +1,37 @@
+use serde::{Deserialize, Deserializer};
+use serde_json::Value;
+
+// The proxy serializes big integers as JSON numbers, serde_json's `arbitrary_precision` keeps the digits of those above `u64::MAX`.
+pub(crate) fn deserialize_amount<'de, D: Deserializer<'de>>(deserializer: D) -> Result<String, D::Error> {
+    let value = match Value::deserialize(deserializer)? {
+        Value::String(value) => value,
+        Value::Null => String::new(),
+        value => value.to_string(),
+    };
+
+    Ok(value)
+}
+
+#[cfg(test)]
+mod tests {
+    use serde::Deserialize;
+
+    use crate::utils::amount::deserialize_amount;
+
+    #[derive(Deserialize)]
+    struct Amount {
+        #[serde(deserialize_with = "deserialize_amount")]
+        value: String,
+    }
+
+    #[test]
+    fn test_deserialize_amount() {
+        let parse = |text: &str| serde_json::from_str::<Amount>(text).unwrap().value;
+
+        assert_eq!(parse(r#"{"value":1500}"#), "1500");
+        assert_eq!(parse(r#"{"value":"1500"}"#), "1500");
+        assert_eq!(parse(r#"{"value":null}"#), "");
+        assert_eq!(parse(r#"{"value":2500000000000000000000}"#), "2500000000000000000000");
+        assert_eq!(parse(r#"{"value":18446744073709551617}"#), "18446744073709551617");
+    }
+}
@@ -0,0 +1,21 @@
+use base64::Engine;
+use base64::engine::general_purpose::STANDARD as BASE64;
+
+#[derive(Clone, PartialEq, Debug)]
+pub(crate) struct BasicAuthCredentials {
+    pub(crate) username: String,
+    pub(crate) password: String,
+}
+
+impl BasicAuthCredentials {
+    pub(crate) fn new(username: impl Into<String>, password: impl Into<String>) -> BasicAuthCredentials {
+        BasicAuthCredentials {
+            username: username.into(),
+            password: password.into(),
+        }
+    }
+
+    pub(crate) fn authorization_header(&self) -> String {
+        format!("Basic {}", BASE64.encode(format!("{}:{}", self.username, self.password)))
+    }
+}
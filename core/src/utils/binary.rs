@@ -0,0 +1,194 @@
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process::{Command, Stdio};
+use std::time::{Duration, Instant};
+
+use crate::{ASSETS_PATH, SIMULATOR_PLATFORM};
+use crate::error::fs::FsError;
+use crate::error::lib::LibError;
+use crate::error::process::ProcessError;
+use crate::utils::fs::copy_dir_recursive;
+
+pub(crate) const CHAIN_SIMULATOR_NAME: &str = "chainsimulator";
+pub(crate) const CONFIG_FOLDER: &str = "config";
+
+const BINARY_ENV_VAR: &str = "MX_CHAIN_SIMULATOR_BIN";
+const CACHE_DIR_ENV_VAR: &str = "MX_CHAIN_SIMULATOR_CACHE_DIR";
+
+const VERSION_COMMAND_TIMEOUT: Duration = Duration::from_secs(5);
+
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub enum SimulatorBinarySource {
+    Options,
+    EnvironmentVariable,
+    BuildAssets,
+    UserCache,
+    Path,
+}
+
+#[derive(Clone, PartialEq, Debug)]
+pub struct SimulatorBinary {
+    pub path: PathBuf,
+    pub source: SimulatorBinarySource,
+    pub version: Option<String>,
+}
+
+// The build's `OUT_DIR` is mirrored into the user cache, which takes over once the target dir is cleaned.
+pub(crate) fn resolve_assets_dir() -> Result<(PathBuf, SimulatorBinarySource), LibError> {
+    let build_assets_dir = PathBuf::from(ASSETS_PATH);
+    let cached_assets_dir = get_user_cache_dir().map(|dir| dir.join("assets"));
+
+    if build_assets_dir.join(CONFIG_FOLDER).is_dir() {
+        if let Some(cached_assets_dir) = &cached_assets_dir {
+            populate_user_cache(&build_assets_dir, cached_assets_dir);
+        }
+
+        return Ok((build_assets_dir, SimulatorBinarySource::BuildAssets));
+    }
+
+    if let Some(cached_assets_dir) = cached_assets_dir {
+        if cached_assets_dir.join(CONFIG_FOLDER).is_dir() {
+            return Ok((cached_assets_dir, SimulatorBinarySource::UserCache));
+        }
+    }
+
+    Err(FsError::CannotFindAssets { searched_path: ASSETS_PATH.to_string() }.into())
+}
+
+pub(crate) fn resolve_simulator_binary(binary_path: Option<&Path>, assets_dir: &Path, assets_source: SimulatorBinarySource) -> Result<SimulatorBinary, LibError> {
+    let (path, source) = if let Some(binary_path) = binary_path {
+        (binary_path.to_path_buf(), SimulatorBinarySource::Options)
+    } else if let Some(env_path) = std::env::var_os(BINARY_ENV_VAR) {
+        (PathBuf::from(env_path), SimulatorBinarySource::EnvironmentVariable)
+    } else if assets_dir.join(CHAIN_SIMULATOR_NAME).is_file() {
+        (assets_dir.join(CHAIN_SIMULATOR_NAME), assets_source)
+    } else if let Some(path) = find_in_path(CHAIN_SIMULATOR_NAME) {
+        (path, SimulatorBinarySource::Path)
+    } else if SIMULATOR_PLATFORM.is_none() {
+        let error = ProcessError::UnsupportedOSAndArch {
+            os: std::env::consts::OS.to_string(),
+            arch: std::env::consts::ARCH.to_string(),
+        };
+
+        return Err(error.into());
+    } else {
+        return Err(ProcessError::SimulatorBinaryNotFound.into());
+    };
+
+    if !path.is_file() {
+        return Err(ProcessError::SimulatorBinaryNotFound.into());
+    }
+
+    let version = get_binary_version(&path);
+
+    Ok(SimulatorBinary {
+        path,
+        source,
+        version,
+    })
+}
+
+fn get_user_cache_dir() -> Option<PathBuf> {
+    let base_dir = match std::env::var_os(CACHE_DIR_ENV_VAR) {
+        Some(dir) => PathBuf::from(dir),
+        None => {
+            let cache_home = std::env::var_os("XDG_CACHE_HOME")
+                .map(PathBuf::from)
+                .or_else(|| std::env::var_os("HOME").map(|home| PathBuf::from(home).join(".cache")))?;
+
+            cache_home.join("mx-chain-simulator-interface-rs")
+        }
+    };
+
+    Some(base_dir.join(env!("CARGO_PKG_VERSION")))
+}
+
+fn populate_user_cache(build_assets_dir: &Path, cached_assets_dir: &Path) {
+    if cached_assets_dir.join(CONFIG_FOLDER).is_dir() {
+        return;
+    }
+
+    // Several simulators may start at the same time, so the copy is done in a private directory then renamed.
+    // The cache is only a fallback, any failure is ignored.
+    let Some(parent) = cached_assets_dir.parent() else {
+        return;
+    };
+    let staging_dir = parent.join(format!("assets.tmp-{}", std::process::id()));
+
+    if copy_dir_recursive(build_assets_dir, &staging_dir).is_err() || fs::rename(&staging_dir, cached_assets_dir).is_err() {
+        let _ = fs::remove_dir_all(&staging_dir);
+    }
+}
+
+fn find_in_path(name: &str) -> Option<PathBuf> {
+    let path_env = std::env::var_os("PATH")?;
+
+    std::env::split_paths(&path_env)
+        .map(|dir| dir.join(name))
+        .find(|path| path.is_file())
+}
+
+fn get_binary_version(path: &Path) -> Option<String> {
+    let mut child = Command::new(path)
+        .arg("--version")
+        .stdout(Stdio::piped())
+        .stderr(Stdio::null())
+        .spawn()
+        .ok()?;
+
+    // An unknown binary might ignore the flag and start a whole network, so we don't wait forever.
+    let deadline = Instant::now() + VERSION_COMMAND_TIMEOUT;
+    loop {
+        match child.try_wait() {
+            Ok(Some(_)) => break,
+            Ok(None) if Instant::now() < deadline => std::thread::sleep(Duration::from_millis(10)),
+            _ => {
+                let _ = child.kill();
+                let _ = child.wait();
+                return None;
+            }
+        }
+    }
+
+    let output = child.wait_with_output().ok()?;
+    let stdout = String::from_utf8(output.stdout).ok()?;
+
+    // The output looks like "chainsimulator version v1.7.9"
+    stdout
+        .split_whitespace()
+        .last()
+        .map(|version| version.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::Path;
+
+    use crate::error::lib::LibError;
+    use crate::error::process::ProcessError;
+    use crate::utils::binary::{CHAIN_SIMULATOR_NAME, resolve_simulator_binary, SimulatorBinarySource};
+    use crate::utils::fs::{get_temp_dir, write_bytes_to_temp_file};
+
+    #[test]
+    fn test_resolve_binary_from_options_takes_precedence() {
+        let assets_dir = get_temp_dir().unwrap();
+        write_bytes_to_temp_file(CHAIN_SIMULATOR_NAME, assets_dir.path(), b"").unwrap();
+
+        let custom_dir = get_temp_dir().unwrap();
+        let custom_binary = write_bytes_to_temp_file("custom-simulator", custom_dir.path(), b"").unwrap();
+
+        let result = resolve_simulator_binary(Some(&custom_binary), assets_dir.path(), SimulatorBinarySource::BuildAssets).unwrap();
+
+        assert_eq!(result.path, custom_binary);
+        assert_eq!(result.source, SimulatorBinarySource::Options);
+    }
+
+    #[test]
+    fn test_resolve_binary_from_options_not_found() {
+        let assets_dir = get_temp_dir().unwrap();
+
+        let result = resolve_simulator_binary(Some(Path::new("/this/path/does/not/exist")), assets_dir.path(), SimulatorBinarySource::BuildAssets);
+
+        assert_eq!(result, Err(LibError::Process(ProcessError::SimulatorBinaryNotFound)));
+    }
+}
@@ -1,3 +1,4 @@
+use std::{fs, io};
 use std::fs::File;
 use std::io::Write;
 use std::path::{Path, PathBuf};
@@ -33,4 +34,25 @@ pub(crate) fn write_bytes_to_temp_file(
     drop(file);
 
     Ok(temp_file_path)
+}
+
+pub(crate) fn copy_dir_recursive(src: &Path, dst: &Path) -> io::Result<()> {
+    if !dst.exists() {
+        fs::create_dir_all(dst)?;
+    }
+
+    for entry in fs::read_dir(src)? {
+        let entry = entry?;
+        let ty = entry.file_type()?;
+        let src_path = entry.path();
+        let dst_path = dst.join(entry.file_name());
+
+        if ty.is_file() {
+            fs::copy(&src_path, &dst_path)?;
+        } else if ty.is_dir() {
+            copy_dir_recursive(&src_path, &dst_path)?;
+        }
+    }
+
+    Ok(())
 }
\ No newline at end of file
@@ -0,0 +1,41 @@
+use std::convert::Infallible;
+use std::future::Future;
+use std::net::{SocketAddr, TcpListener};
+
+use hyper::{Body, Request, Response, Server};
+use hyper::service::{make_service_fn, service_fn};
+use tokio::task::JoinHandle;
+
+// The servers receiving the nodes' data listen on a random port of the loopback, the nodes are given the resulting address.
+pub(crate) fn bind_local_listener() -> Option<(TcpListener, SocketAddr)> {
+    let listener = TcpListener::bind("127.0.0.1:0").ok()?;
+    listener.set_nonblocking(true).ok()?;
+
+    let address = listener.local_addr().ok()?;
+
+    Some((listener, address))
+}
+
+pub(crate) fn spawn_local_http_server<F, Fut>(handle: F) -> Option<(SocketAddr, JoinHandle<()>)>
+where
+    F: Fn(Request<Body>) -> Fut + Clone + Send + 'static,
+    Fut: Future<Output = Result<Response<Body>, Infallible>> + Send + 'static,
+{
+    let (listener, address) = bind_local_listener()?;
+    let server = Server::from_tcp(listener).ok()?;
+
+    let make_service = make_service_fn(move |_| {
+        let handle = handle.clone();
+
+        async move {
+            Ok::<_, Infallible>(service_fn(handle))
+        }
+    });
+
+    let server = server.serve(make_service);
+    let task = tokio::spawn(async move {
+        let _ = server.await;
+    });
+
+    Some((address, task))
+}
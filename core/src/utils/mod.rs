@@ -1,2 +1,8 @@
+pub(crate) mod address;
+pub(crate) mod amount;
+pub(crate) mod basic_auth;
+pub(crate) mod bls;
+pub(crate) mod binary;
 pub(crate) mod fs;
+pub(crate) mod local_server;
 pub(crate) mod process;
\ No newline at end of file
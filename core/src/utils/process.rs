@@ -1,23 +1,58 @@
-use std::{fs, io};
+use std::fs;
 use std::ffi::OsStr;
 use std::fs::Permissions;
-use std::path::Path;
+use std::path::{Path, PathBuf};
 use std::process::{Child, Command, Stdio};
 
-use crate::ASSETS_PATH;
 use crate::error::fs::FsError;
 use crate::error::lib::LibError;
 use crate::error::process::ProcessError;
-use crate::utils::fs::write_bytes_to_temp_file;
+use crate::utils::binary::{CHAIN_SIMULATOR_NAME, CONFIG_FOLDER, resolve_assets_dir, resolve_simulator_binary, SimulatorBinary, SimulatorBinarySource};
+use crate::utils::fs::{copy_dir_recursive, write_bytes_to_temp_file};
 
-const CHAIN_SIMULATOR_NAME: &str = "chainsimulator";
-
-const CONFIG_FOLDER: &str = "config";
 const CONFIG_NAME: &str = "config.toml";
 
-pub fn prepare_temp_dir_for_simulator(tempdir_path: &Path) -> Result<(), LibError> {
-    copy_dir_recursive(&Path::new(ASSETS_PATH), tempdir_path)
-        .map_err(|_| FsError::CannotCopyAssets.into())
+// File system and process work, such as copying the assets, must not block the runtime's workers.
+pub(crate) async fn run_blocking<T, F>(function: F) -> Result<T, LibError>
+where
+    F: FnOnce() -> Result<T, LibError> + Send + 'static,
+    T: Send + 'static,
+{
+    match tokio::task::spawn_blocking(function).await {
+        Ok(result) => result,
+        Err(error) => std::panic::resume_unwind(error.into_panic()),
+    }
+}
+
+pub fn prepare_temp_dir_for_simulator(tempdir_path: &Path, binary_path: Option<&Path>) -> Result<SimulatorBinary, LibError> {
+    let (assets_dir, assets_source) = copy_assets_to_temp_dir(tempdir_path)?;
+
+    let binary = resolve_simulator_binary(binary_path, &assets_dir, assets_source)?;
+
+    if !matches!(binary.source, SimulatorBinarySource::BuildAssets | SimulatorBinarySource::UserCache) {
+        copy_external_binary(&binary.path, tempdir_path)?;
+    }
+
+    Ok(binary)
+}
+
+pub(crate) fn copy_assets_to_temp_dir(tempdir_path: &Path) -> Result<(PathBuf, SimulatorBinarySource), LibError> {
+    let (assets_dir, assets_source) = resolve_assets_dir()?;
+
+    copy_dir_recursive(&assets_dir, tempdir_path)
+        .map_err(|_| LibError::from(FsError::CannotCopyAssets))?;
+
+    Ok((assets_dir, assets_source))
+}
+
+pub(crate) fn write_simulator_config(tempdir_path: &Path, config_content: &[u8]) -> Result<(), LibError> {
+    write_bytes_to_temp_file(
+        CONFIG_NAME,
+        tempdir_path.join(CONFIG_FOLDER).as_path(),
+        config_content,
+    )?;
+
+    Ok(())
 }
 
 pub fn spawn_simulator_process<Args, S>(tempdir_path: &Path, config_content: &[u8], args: Args) -> Result<Child, LibError>
@@ -42,37 +77,40 @@ where
         };
     }
 
-    write_bytes_to_temp_file(
-        CONFIG_NAME,
-        tempdir_path.join(CONFIG_FOLDER).as_path(),
-        config_content,
-    )?;
+    write_simulator_config(tempdir_path, config_content)?;
 
     Command::new(&chain_simulator_path)
-        .current_dir(&tempdir_path)
+        .current_dir(tempdir_path)
         .args(args)
         .stdout(Stdio::piped())
         .spawn()
         .map_err(|_| ProcessError::CannotSpawnProcess.into())
 }
 
-fn copy_dir_recursive(src: &Path, dst: &Path) -> io::Result<()> {
-    if !dst.exists() {
-        fs::create_dir_all(dst)?;
-    }
+// The VM libraries next to the binary are copied too.
+fn copy_external_binary(binary_path: &Path, tempdir_path: &Path) -> Result<(), LibError> {
+    fs::copy(binary_path, tempdir_path.join(CHAIN_SIMULATOR_NAME))
+        .map_err(|_| LibError::from(ProcessError::CannotCopyAssets))?;
+
+    let Some(binary_dir) = binary_path.parent() else {
+        return Ok(());
+    };
 
-    for entry in fs::read_dir(src)? {
-        let entry = entry?;
-        let ty = entry.file_type()?;
-        let src_path = entry.path();
-        let dst_path = dst.join(entry.file_name());
+    let Ok(entries) = fs::read_dir(binary_dir) else {
+        return Ok(());
+    };
 
-        if ty.is_file() {
-            fs::copy(&src_path, &dst_path)?;
-        } else if ty.is_dir() {
-            copy_dir_recursive(&src_path, &dst_path)?;
+    for entry in entries.flatten() {
+        let file_name = entry.file_name();
+        let is_vm_library = file_name
+            .to_str()
+            .is_some_and(|name| name.starts_with("libwasmer") || name.starts_with("libvmexeccapi"));
+
+        if is_vm_library {
+            fs::copy(entry.path(), tempdir_path.join(&file_name))
+                .map_err(|_| LibError::from(ProcessError::CannotCopyAssets))?;
         }
     }
 
     Ok(())
-}
\ No newline at end of file
+}